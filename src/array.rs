@@ -1,21 +1,44 @@
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::convert::TryFrom;
 use std::fmt;
+use std::hash::Hash;
+use std::iter;
 use std::mem;
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::ptr;
 use std::slice;
 
-use tll::ternary::{Nat, Pred, NatPred, Triple, NatTriple, Zero, One, Two};
+use tll::ternary::{Nat, Pred, NatPred, Succ, NatSucc, Triple, NatTriple, Term, Zero, One, Two,
+                   U2};
 use tll_iterator::{SizedIterator, NonEmpty, FromSizedIterator};
 
-use guillotine::*;
+use bounded::BoundedNat;
+use builder::ArrayBuilder;
+use length::LengthMismatch;
+use ops::{Sum, NatSum, Diff, NatDiff, Product, NatProduct, Quotient, Remainder, NatDivMod,
+          NatLe, NatLt, NatValue, IsEven, IsNonZero, IsPowerOfTwo};
+use permutation::{self, Permutation};
+use reduce::AssociativeOp;
 use storage::*;
 
 
 /// The `array![]` macro provides a convenient way to construct `Array`s from scratch. It can be
-/// invoked similarly to the `vec![]` macro, although `array![]` *does not* provide the "repeat"
-/// syntax (like how `vec![0; 10]` would produce a `Vec` of 10 zeroes).
+/// invoked similarly to the `vec![]` macro, including the "repeat" syntax - except that since the
+/// length of an `Array` lives in its type, it is given as a type-level natural, so `array![0; U8]`
+/// rather than `vec![0; 8]`. Repetition goes through `Array::fill`, and so needs `T: Clone`.
+///
+/// ```
+/// # #[macro_use] extern crate tll_array; fn main() {
+/// use tll_array::tll::ternary::U4;
+///
+/// assert_eq!(*array![1i32, 2, 3], [1, 2, 3]);
+/// assert_eq!(*array![0u32; U4], [0, 0, 0, 0]);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! array {
+    ($x:expr; $l:ty) => ($crate::array::Array::<$l, _>::fill($x));
     (@assign $data:ident $n:expr => $x:expr $(, $xs:expr)*) => (
         ::std::ptr::write(&mut $data[$n], $x); array!(@assign $data ($n + 1) => $($xs),*));
     (@assign $data:ident $n:expr =>) => ();
@@ -32,6 +55,78 @@ macro_rules! array {
 }
 
 
+/// The `array_init!()` macro builds an `Array` of the given type-level length by calling a
+/// function or closure on each index in turn. It is shorthand for `Array::from_fn`, saving a
+/// turbofish when the element type can be inferred.
+///
+/// ```
+/// # #[macro_use] extern crate tll_array; fn main() {
+/// use tll_array::tll::ternary::U4;
+///
+/// let squares = array_init!(|i: usize| (i * i) as f32, U4);
+/// assert_eq!(*squares, [0.0, 1.0, 4.0, 9.0]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! array_init {
+    ($f:expr, $l:ty) => ($crate::array::Array::<$l, _>::from_fn($f));
+}
+
+
+/// The `concat_arrays!()` macro joins any number of `Array`s end to end, by chaining calls to
+/// `Array::concat`. A type-level length may be given after a semicolon, in which case it is
+/// checked against the length of the result; since `Sum` always comes out in canonical form,
+/// `concat_arrays!(a, b; U5)` compiles exactly when the lengths of `a` and `b` add up to five.
+///
+/// ```
+/// # #[macro_use] extern crate tll_array; fn main() {
+/// use tll_array::tll::ternary::U5;
+///
+/// let joined = concat_arrays!(array![1i32], array![2, 3], array![4, 5]; U5);
+/// assert_eq!(*joined, [1, 2, 3, 4, 5]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! concat_arrays {
+    ($a:expr $(, $rest:expr)*; $l:ty) => ({
+        let joined: $crate::array::Array<$l, _> = concat_arrays!($a $(, $rest)*);
+        joined
+    });
+    ($a:expr $(, $rest:expr)*) => ($a $(.concat($rest))*);
+}
+
+
+/// The `zip_arrays![]` macro zips anywhere from two to eight `Array`s of the same length into a
+/// single `Array` of flat tuples, so `zip_arrays![a, b, c]` produces an `Array<L, (A, B, C)>`
+/// rather than the `Array<L, ((A, B), C)>` that chaining `.zip()` by hand would give.
+///
+/// ```
+/// # #[macro_use] extern crate tll_array; fn main() {
+/// let zipped = zip_arrays![array![1i32, 2], array!['a', 'b'], array![true, false]];
+/// assert_eq!(zipped[1], (2, 'b', false));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! zip_arrays {
+    ($a:expr, $b:expr) => ($a.zip($b));
+    ($a:expr, $b:expr, $c:expr) => ($a.zip3($b, $c));
+    ($a:expr, $b:expr, $c:expr, $d:expr) => (
+        $a.zip($b).zip($c).zip($d).map(|(((a, b), c), d)| (a, b, c, d)));
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr) => (
+        $a.zip($b).zip($c).zip($d).zip($e).map(|((((a, b), c), d), e)| (a, b, c, d, e)));
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr) => (
+        $a.zip($b).zip($c).zip($d).zip($e).zip($f)
+            .map(|(((((a, b), c), d), e), f)| (a, b, c, d, e, f)));
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr) => (
+        $a.zip($b).zip($c).zip($d).zip($e).zip($f).zip($g)
+            .map(|((((((a, b), c), d), e), f), g)| (a, b, c, d, e, f, g)));
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr) => (
+        $a.zip($b).zip($c).zip($d).zip($e).zip($f).zip($g).zip($h)
+            .map(|(((((((a, b), c), d), e), f), g), h)| (a, b, c, d, e, f, g, h)));
+    ($($xs:expr),+,) => (zip_arrays!($($xs),+));
+}
+
+
 /// The `Array` struct represents an array the length of which is determined by a type-level `Nat`.
 /// For technical reasons, the `Arrayify<T>` trait is also necessary. Since `Arrayify<T>` includes
 /// `Nat` as a supertrait, it will in most cases suffice to simply use the `Arrayify<T>` on an
@@ -56,6 +151,9 @@ impl<L: Arrayify<T>, T: Clone> Clone for Array<L, T>
 
 impl<L: Arrayify<T>, T: Copy> Copy for Array<L, T> where Reify<L, T>: Copy {}
 
+/// An `Array` stores its elements inline, so it is `Unpin` exactly when its elements are.
+impl<L: Arrayify<T>, T: Unpin> Unpin for Array<L, T> {}
+
 
 impl<L: Arrayify<T>, T> Deref for Array<L, T> {
     type Target = [T];
@@ -71,6 +169,35 @@ impl<L: Arrayify<T>, T> DerefMut for Array<L, T> {
     }
 }
 
+impl<L: Arrayify<T>, T> AsRef<[T]> for Array<L, T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<L: Arrayify<T>, T> AsMut<[T]> for Array<L, T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+
+/// Indexing an `Array<L, T>` with a `BoundedNat<L>` cannot go out of bounds, so it skips the
+/// bounds check that indexing through the slice would perform.
+impl<L: Arrayify<T>, T> Index<BoundedNat<L>> for Array<L, T> {
+    type Output = T;
+
+    fn index(&self, index: BoundedNat<L>) -> &T {
+        unsafe { self.get_unchecked(index.get()) }
+    }
+}
+
+impl<L: Arrayify<T>, T> IndexMut<BoundedNat<L>> for Array<L, T> {
+    fn index_mut(&mut self, index: BoundedNat<L>) -> &mut T {
+        unsafe { self.get_unchecked_mut(index.get()) }
+    }
+}
+
 
 pub trait ArraySplit<L: Arrayify<T> + NatPred, T>
     where Pred<L>: Arrayify<T>
@@ -193,166 +320,2214 @@ impl<L: Arrayify<T>, T> Array<L, T> {
     {
         <Self as ArraySplit<L, T>>::split_last(self)
     }
-}
 
+    /// Borrow the first element of a nonempty `Array`. Unlike the slice method `first`, there's no
+    /// `Option` to unwrap: the `L: IsNonZero` bound rules out the empty case at compile time.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![42i32, 84, 126];
+    /// assert_eq!(*array.front(), 42);
+    /// assert_eq!(*array.back(), 126);
+    /// # }
+    /// ```
+    pub fn front(&self) -> &T
+        where L: IsNonZero
+    {
+        unsafe { self.get_unchecked(0) }
+    }
 
-pub struct ArrayIter<L: Arrayify<T>, T> {
-    data: Guillotine<Array<L, T>>,
-    pos: usize,
-}
+    /// Mutably borrow the first element of a nonempty `Array`.
+    pub fn front_mut(&mut self) -> &mut T
+        where L: IsNonZero
+    {
+        unsafe { self.get_unchecked_mut(0) }
+    }
 
-impl<L: Arrayify<T>, T> Drop for ArrayIter<L, T> {
-    fn drop(&mut self) {
-        unsafe {
-            let mut data = self.data.take().unwrap_unchecked();
-            for i in self.pos..data.len() {
-                ptr::drop_in_place(&mut data[i]);
-            }
-        }
+    /// Borrow the last element of a nonempty `Array`. Like `front`, this can't fail.
+    pub fn back(&self) -> &T
+        where L: IsNonZero
+    {
+        unsafe { self.get_unchecked(L::reify() - 1) }
     }
-}
 
-impl<L: Arrayify<T>, T> Iterator for ArrayIter<L, T> {
-    type Item = T;
+    /// Mutably borrow the last element of a nonempty `Array`.
+    pub fn back_mut(&mut self) -> &mut T
+        where L: IsNonZero
+    {
+        unsafe { self.get_unchecked_mut(L::reify() - 1) }
+    }
 
-    fn next(&mut self) -> Option<T> {
+    /// Split the `Array` apart into its first `K` elements and its remaining `L - K` elements.
+    /// Like `split_first` and `split_last`, this consists only of pointer casts and reads. The
+    /// `L: NatDiff<K>` bound is what ensures that `K <= L`.
+    ///
+    /// Note that this takes the place of the slice method of the same name, which splits at a
+    /// runtime index; for that, go through a slice explicitly, as in `array[..].split_at(k)`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// let (front, back) = array![1i32, 2, 3, 4, 5].split_at::<U2>();
+    /// assert_eq!(*front, [1, 2]);
+    /// assert_eq!(*back, [3, 4, 5]);
+    /// # }
+    /// ```
+    pub fn split_at<K>(self) -> (Array<K, T>, Array<Diff<L, K>, T>)
+        where K: Arrayify<T>,
+              L: NatDiff<K>,
+              Diff<L, K>: Arrayify<T>
+    {
+        let source = mem::ManuallyDrop::new(self);
         unsafe {
-            let data = self.data.as_ref().unwrap_unchecked();
-            if self.pos < data.len() {
-                let next = ptr::read(&data[self.pos]);
-                self.pos += 1;
-                Some(next)
-            } else {
-                None
-            }
+            let ptr = source.as_ptr();
+            (ptr::read(ptr as *const Array<K, T>),
+             ptr::read(ptr.offset(K::reify() as isize) as *const Array<Diff<L, K>, T>))
         }
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (L::reify(), Some(L::reify()))
+    /// Get a raw pointer to the first element of the `Array`, which is the same address as the
+    /// `Array` itself. This is what the slice method of the same name would give, without going
+    /// through the slice.
+    pub fn as_ptr(&self) -> *const T {
+        self as *const Self as *const T
     }
-}
 
-impl<L: Arrayify<T>, T> IntoIterator for Array<L, T> {
-    type IntoIter = ArrayIter<L, T>;
-    type Item = T;
-
-    fn into_iter(self) -> ArrayIter<L, T> {
-        ArrayIter {
-            data: Alive(self),
-            pos: 0,
-        }
+    /// Get a raw mutable pointer to the first element of the `Array`.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut Self as *mut T
     }
-}
-
-impl<L: Arrayify<T>, T> ExactSizeIterator for ArrayIter<L, T> {}
-
 
-impl<L: Arrayify<T>, T: fmt::Debug> fmt::Debug for Array<L, T> {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        self.deref().fmt(fmt)
+    /// Iterate over references to the elements of the `Array`. This is the same iterator the slice
+    /// method gives, provided directly so as not to depend on deref coercion to find it.
+    pub fn iter(&self) -> slice::Iter<T> {
+        self.deref().iter()
     }
-}
 
+    /// Iterate over mutable references to the elements of the `Array`.
+    pub fn iter_mut(&mut self) -> slice::IterMut<T> {
+        self.deref_mut().iter_mut()
+    }
 
-impl<L: Arrayify<T>, T> SizedIterator<L> for Array<L, T> {}
+    /// Transform every element of the `Array`, producing an `Array` of the same length.
+    ///
+    /// If `f` panics, nothing is leaked: the results produced so far and the elements not yet
+    /// passed to `f` are all dropped during unwinding.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2, 3].map(|x| x * 2);
+    /// assert_eq!(*array, [2, 4, 6]);
+    /// # }
+    /// ```
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Array<L, U>
+        where L: Arrayify<U>
+    {
+        ArrayBuilder::collect(self.into_iter().map(f))
+    }
 
-impl<L: Arrayify<T> + NatPred, T> NonEmpty<Zero<L>> for Array<Zero<L>, T>
-    where Array<Zero<L>, T>: ArraySplit<Zero<L>, T>,
-          Pred<L>: Arrayify<T>
-{
-    type Next = Array<Two<Pred<L>>, T>;
+    /// Build an `Array` by calling `f` on each index from `0` up to `L`, in order. If `f` panics,
+    /// the elements produced so far are dropped.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U4;
+    ///
+    /// let squares = Array::<U4, usize>::from_fn(|i| i * i);
+    /// assert_eq!(*squares, [0, 1, 4, 9]);
+    /// # }
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        ArrayBuilder::collect((0..L::reify()).map(f))
+    }
 
-    fn next(self) -> (T, Array<Two<Pred<L>>, T>) {
-        self.split_first()
+    /// Build an `Array` with every element a clone of `value`, like `vec![value; L]`. The last
+    /// element is `value` itself, so only `L - 1` clones are made.
+    ///
+    /// Since this takes no `self`, calling `.fill(x)` on an existing `Array` still goes to the
+    /// slice method, which overwrites every element in place.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let array = Array::<U3, String>::fill(String::from("x"));
+    /// assert_eq!(*array, ["x", "x", "x"]);
+    /// # }
+    /// ```
+    pub fn fill(value: T) -> Self
+        where T: Clone
+    {
+        let mut builder = ArrayBuilder::new();
+        if L::reify() > 0 {
+            for _ in 1..L::reify() {
+                builder.push(value.clone());
+            }
+            builder.push(value);
+        }
+        builder.finish()
     }
-}
 
-impl<L: Arrayify<T> + NatTriple, T> NonEmpty<One<L>> for Array<One<L>, T>
-    where Array<One<L>, T>: ArraySplit<One<L>, T>,
-          Triple<L>: Arrayify<T>
-{
-    type Next = Array<Triple<L>, T>;
+    /// Build an `Array` with every element a copy of `value`.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U4;
+    ///
+    /// assert_eq!(*Array::<U4, f32>::splat(0.5), [0.5; 4]);
+    /// # }
+    /// ```
+    pub fn splat(value: T) -> Self
+        where T: Copy
+    {
+        ArrayBuilder::collect(iter::repeat(value))
+    }
 
-    fn next(self) -> (T, Array<Triple<L>, T>) {
-        self.split_first()
+    /// Combine every element of the `Array` into an accumulator, from left to right, moving each
+    /// element out rather than borrowing it.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let words = array![String::from("foo"), String::from("bar")];
+    /// assert_eq!(words.fold(String::new(), |acc, s| acc + &s), "foobar");
+    /// # }
+    /// ```
+    pub fn fold<B, F: FnMut(B, T) -> B>(self, init: B, f: F) -> B {
+        self.into_iter().fold(init, f)
     }
-}
 
-impl<L: Arrayify<T>, T> NonEmpty<Two<L>> for Array<Two<L>, T>
-    where Array<Two<L>, T>: ArraySplit<Two<L>, T>
-{
-    type Next = Array<One<L>, T>;
+    /// Transform a borrow of every element of the `Array`, producing a new `Array` of the same
+    /// length and leaving `self` untouched.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let names = array![String::from("foo"), String::from("quux")];
+    /// let lengths = names.map_ref(|s| s.len());
+    /// assert_eq!(*lengths, [3, 4]);
+    /// assert_eq!(names[1], "quux");
+    /// # }
+    /// ```
+    pub fn map_ref<'a, U, F: FnMut(&'a T) -> U>(&'a self, f: F) -> Array<L, U>
+        where L: Arrayify<U>
+    {
+        ArrayBuilder::collect(self.iter().map(f))
+    }
 
-    fn next(self) -> (T, Array<One<L>, T>) {
-        self.split_first()
+    /// Update every element of the `Array` in place.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let mut array = array![-5i32, 3, 12];
+    /// array.map_in_place(|x| *x = (*x).max(0).min(10));
+    /// assert_eq!(*array, [0, 3, 10]);
+    /// # }
+    /// ```
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.iter_mut().for_each(f)
     }
-}
 
-impl<L: Arrayify<T>, T> FromSizedIterator<L, T> for Array<L, T> {
-    fn from_sized_iter<I: SizedIterator<L, Item = T>>(iter: I) -> Self {
-        let mut array: Array<L, T>;
+    /// Join two `Array`s end to end into one whose length is the sum of theirs. The elements are
+    /// moved over with two `memcpy`s.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let joined = array![1i32, 2].concat(array![3, 4, 5]);
+    /// assert_eq!(*joined, [1, 2, 3, 4, 5]);
+    /// # }
+    /// ```
+    pub fn concat<M: Arrayify<T>>(self, other: Array<M, T>) -> Array<Sum<L, M>, T>
+        where L: NatSum<M>,
+              Sum<L, M>: Arrayify<T>
+    {
+        let front = mem::ManuallyDrop::new(self);
+        let back = mem::ManuallyDrop::new(other);
         unsafe {
-            array = mem::uninitialized();
-            for (i, v) in iter.into_iter().enumerate() {
-                ptr::write(&mut array[i], v);
-            }
+            let mut joined = mem::MaybeUninit::<Array<Sum<L, M>, T>>::uninit();
+            let dst = joined.as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(front.as_ptr(), dst, L::reify());
+            ptr::copy_nonoverlapping(back.as_ptr(), dst.offset(L::reify() as isize), M::reify());
+            joined.assume_init()
         }
-        array
     }
-}
-
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Append `value` to the end of the `Array`, producing one which is a single element longer.
+    /// Together with `push_front`, this builds up an `Array` one element at a time with the
+    /// length tracked all along in the type.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2].push_back(3);
+    /// assert_eq!(*array, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn push_back(self, value: T) -> Array<Succ<L>, T>
+        where L: NatSucc,
+              Succ<L>: Arrayify<T>
+    {
+        let init = mem::ManuallyDrop::new(self);
+        unsafe {
+            let mut pushed = mem::MaybeUninit::<Array<Succ<L>, T>>::uninit();
+            let dst = pushed.as_mut_ptr() as *mut T;
+            ptr::copy_nonoverlapping(init.as_ptr(), dst, L::reify());
+            ptr::write(dst.offset(L::reify() as isize), value);
+            pushed.assume_init()
+        }
+    }
 
-    #[test]
-    fn split_first_1() {
-        let array = array![42i32];
-        assert_eq!(array.len(), 1);
-        let (head, array) = array.split_first();
-        assert_eq!(array.len(), 0);
-        assert_eq!(head, 42);
+    /// Prepend `value` to the start of the `Array`, shifting everything else one place along.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![2i32, 3].push_front(1);
+    /// assert_eq!(*array, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn push_front(self, value: T) -> Array<Succ<L>, T>
+        where L: NatSucc,
+              Succ<L>: Arrayify<T>
+    {
+        let tail = mem::ManuallyDrop::new(self);
+        unsafe {
+            let mut pushed = mem::MaybeUninit::<Array<Succ<L>, T>>::uninit();
+            let dst = pushed.as_mut_ptr() as *mut T;
+            ptr::write(dst, value);
+            ptr::copy_nonoverlapping(tail.as_ptr(), dst.offset(1), L::reify());
+            pushed.assume_init()
+        }
     }
 
-    #[test]
-    fn split_first_2() {
-        let array = array![42i32, 84];
-        assert_eq!(array.len(), 2);
-        let (head, array) = array.split_first();
-        assert_eq!(array.len(), 1);
-        assert_eq!(head, 42);
+    /// Zip two `Array`s of the same length together into an `Array` of pairs.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let zipped = array![1i32, 2, 3].zip(array!['a', 'b', 'c']);
+    /// assert_eq!(zipped[2], (3, 'c'));
+    /// # }
+    /// ```
+    pub fn zip<U>(self, other: Array<L, U>) -> Array<L, (T, U)>
+        where L: Arrayify<U> + Arrayify<(T, U)>
+    {
+        ArrayBuilder::collect(self.into_iter().zip(other))
     }
 
-    #[test]
-    fn split_first_3() {
-        let array = array![42i32, 84, 126];
-        assert_eq!(array.len(), 3);
-        let (head, array) = array.split_first();
-        assert_eq!(array.len(), 2);
-        assert_eq!(head, 42);
+    /// Apply a function to every overlapping window of `W` consecutive elements, producing an
+    /// `Array` of the `L - W + 1` results. Each window is handed to the function as an
+    /// `&Array<W, T>` borrowed straight out of `self`, so its length is part of its type and no
+    /// elements need to be cloned.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// let array = array![1i32, 2, 3, 4];
+    /// let sums = array.map_windows::<U2, _, _>(|w| w[0] + w[1]);
+    /// assert_eq!(*sums, [3, 5, 7]);
+    /// # }
+    /// ```
+    pub fn map_windows<W, U, F>(&self, mut f: F) -> Array<Diff<L, Pred<W>>, U>
+        where W: Arrayify<T> + NatPred,
+              L: NatDiff<Pred<W>>,
+              Diff<L, Pred<W>>: Arrayify<U>,
+              F: FnMut(&Array<W, T>) -> U
+    {
+        ArrayBuilder::collect(self.windows(W::reify())
+            .map(|window| f(unsafe { &*(window.as_ptr() as *const Array<W, T>) })))
     }
 
-    #[test]
-    fn split_first_8() {
-        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
-        assert_eq!(array.len(), 8);
-        let (head, array) = array.split_first();
-        assert_eq!(array.len(), 7);
-        assert_eq!(head, 42);
+    /// Find the indices of the smallest and largest elements of the `Array` in a single pass,
+    /// returning `(None, None)` if it is empty. Ties go to the first such element in either case.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![3i32, 1, 4, 1, 5, 9, 2, 6];
+    /// assert_eq!(array.argmin_max(), (Some(1), Some(5)));
+    /// # }
+    /// ```
+    pub fn argmin_max(&self) -> (Option<usize>, Option<usize>)
+        where T: PartialOrd
+    {
+        if self.is_empty() {
+            return (None, None);
+        }
+
+        let (mut min, mut max) = (0, 0);
+        for (i, x) in self.iter().enumerate().skip(1) {
+            if *x < self[min] {
+                min = i;
+            }
+            if *x > self[max] {
+                max = i;
+            }
+        }
+        (Some(min), Some(max))
     }
 
-    #[test]
-    fn split_first_9() {
-        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378];
-        assert_eq!(array.len(), 9);
-        let (head, array) = array.split_first();
-        assert_eq!(array.len(), 8);
-        assert_eq!(head, 42);
+    /// Count the elements of the `Array` satisfying a predicate.
+    pub fn count_if<F: FnMut(&T) -> bool>(&self, mut f: F) -> usize {
+        self.iter().filter(|x| f(x)).count()
     }
 
-    #[test]
+    /// Count the elements of the `Array` satisfying a predicate, keeping hold of the fact that
+    /// the count can be at most `L`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2, 3, 4, 5];
+    /// let evens = array.count_if_typed(|x| x % 2 == 0);
+    /// assert_eq!(usize::from(evens), 2);
+    /// # }
+    /// ```
+    pub fn count_if_typed<F: FnMut(&T) -> bool>(&self, f: F) -> BoundedNat<Succ<L>>
+        where L: NatSucc
+    {
+        unsafe { BoundedNat::new_unchecked(self.count_if(f)) }
+    }
+
+    /// Reorder the elements of the `Array` according to the permutation `P`, so that element `i`
+    /// of the result is element `P::get(i)` of `self`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::permutation::Reversal;
+    ///
+    /// let array = array![1i32, 2, 3, 4].apply_permutation::<Reversal>();
+    /// assert_eq!(*array, [4, 3, 2, 1]);
+    /// # }
+    /// ```
+    pub fn apply_permutation<P: Permutation<L>>(self) -> Self
+        where L: Arrayify<usize>
+    {
+        debug_assert!(permutation::is_bijection::<L, P>());
+
+        // Every index is worked out before anything is moved, so that should `P::get` panic,
+        // `self` is still whole and gets dropped as usual.
+        let indices: Array<L, usize> = ArrayBuilder::collect((0..L::reify()).map(P::get));
+        let source = mem::ManuallyDrop::new(self);
+        ArrayBuilder::collect(indices.iter().map(|&i| unsafe { ptr::read(&source[i]) }))
+    }
+
+    /// Rotate the elements of the `Array` one place to the left, moving the first element to the
+    /// end.
+    ///
+    /// For an `Array`, whose elements all share one type, this is nothing more than a
+    /// `rotate_left(1)` which can be chained. It is kept as a separate, consuming operation because
+    /// a heterogeneous array (where each position may have its own type) would have to rotate
+    /// its element *types* along with the values, turning a `(T0, T1, T2)`-shaped array into a
+    /// `(T1, T2, T0)`-shaped one - and that only works when the result is a new value.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert_eq!(*array![1i32, 2, 3].rotate_by_one(), [2, 3, 1]);
+    /// # }
+    /// ```
+    pub fn rotate_by_one(mut self) -> Self {
+        if !self.is_empty() {
+            self.rotate_left(1);
+        }
+        self
+    }
+
+    /// Rotate the elements of the `Array` `K` places to the left, so that element `i` of the
+    /// result is element `(i + K) % L` of the original. The amount is part of the type and bounded
+    /// by `K <= L`, so it can't quietly wrap around the way a runtime amount would; rotating by
+    /// exactly `L` leaves the `Array` as it is.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// assert_eq!(*array![1i32, 2, 3, 4, 5].rotate_left_typed::<U2>(), [3, 4, 5, 1, 2]);
+    /// # }
+    /// ```
+    pub fn rotate_left_typed<K: NatLe<L>>(mut self) -> Self {
+        self.deref_mut().rotate_left(K::reify());
+        self
+    }
+
+    /// Rotate the elements of the `Array` `K` places to the right, undoing the effect of
+    /// `rotate_left_typed::<K>`. Element `(i + K) % L` of the result is element `i` of the
+    /// original.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// assert_eq!(*array![1i32, 2, 3, 4, 5].rotate_right_typed::<U2>(), [4, 5, 1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn rotate_right_typed<K: NatLe<L>>(mut self) -> Self {
+        self.deref_mut().rotate_right(K::reify());
+        self
+    }
+
+    /// Reverse the order of the elements of the `Array`, handing it back so that calls can be
+    /// chained. Note that this takes the place of the slice method of the same name, which
+    /// reverses in place; for that, see `reverse_in_place`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert_eq!(*array![1i32, 2, 3].reverse(), [3, 2, 1]);
+    /// # }
+    /// ```
+    #[must_use = "this returns the reversed `Array`; use `reverse_in_place` to reverse in place"]
+    pub fn reverse(mut self) -> Self {
+        self.reverse_in_place();
+        self
+    }
+
+    /// Reverse the order of the elements of the `Array` in place.
+    pub fn reverse_in_place(&mut self) {
+        <[T]>::reverse(self)
+    }
+
+    /// Move the elements of the `Array` onto the heap as a boxed slice. The elements are moved
+    /// with a single `memcpy`, into an allocation made at exactly the right size up front.
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        let source = mem::ManuallyDrop::new(self);
+        let mut vec = Vec::with_capacity(L::reify());
+        unsafe {
+            ptr::copy_nonoverlapping(source.as_ptr(), vec.as_mut_ptr(), L::reify());
+            vec.set_len(L::reify());
+        }
+        vec.into_boxed_slice()
+    }
+
+    /// Move the elements of the `Array` into a `Vec`, which will have a capacity of exactly `L`.
+    /// This is the same as `Vec::from`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let v = array![42i32, 84, 126].into_vec();
+    /// assert_eq!(v, [42, 84, 126]);
+    /// # }
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        Vec::from(self)
+    }
+
+    /// Clone the elements of the `Array` into a `Vec`, leaving the `Array` as it is. This behaves
+    /// exactly as the slice method of the same name does.
+    pub fn to_vec(&self) -> Vec<T>
+        where T: Clone
+    {
+        self.deref().to_vec()
+    }
+
+    /// Move the `Array` onto the heap. For very large `Array`s, it may be better to avoid having
+    /// them on the stack in the first place; see `try_from_boxed_slice`.
+    pub fn into_array_box(self) -> Box<Array<L, T>> {
+        Box::new(self)
+    }
+
+    /// Reinterpret a boxed slice as a boxed `Array`, reusing its allocation. This is undefined
+    /// behavior if the slice does not have exactly `L` elements.
+    pub unsafe fn from_boxed_slice_unchecked(b: Box<[T]>) -> Box<Array<L, T>> {
+        Box::from_raw(Box::into_raw(b) as *mut T as *mut Array<L, T>)
+    }
+
+    /// Reinterpret a boxed slice as a boxed `Array`, reusing its allocation, if it has exactly `L`
+    /// elements. Otherwise, the boxed slice is handed back untouched.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U4;
+    ///
+    /// let boxed = vec![1i32, 2, 3, 4].into_boxed_slice();
+    /// let array = Array::<U4, _>::try_from_boxed_slice(boxed).unwrap();
+    /// assert_eq!(array[3], 4);
+    /// # }
+    /// ```
+    pub fn try_from_boxed_slice(b: Box<[T]>) -> Result<Box<Array<L, T>>, Box<[T]>> {
+        if b.len() == L::reify() {
+            Ok(unsafe { Array::from_boxed_slice_unchecked(b) })
+        } else {
+            Err(b)
+        }
+    }
+
+    /// Resize the `Array` to a new length `M`, either dropping elements off of the end (if `M` is
+    /// shorter than `L`) or filling out the end with clones of `pad` (if `M` is longer).
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::{U2, U5};
+    ///
+    /// let shorter: Array<U2, i32> = array![1, 2, 3].truncate_or_pad(0);
+    /// assert_eq!(*shorter, [1, 2]);
+    /// let longer: Array<U5, i32> = array![1, 2, 3].truncate_or_pad(0);
+    /// assert_eq!(*longer, [1, 2, 3, 0, 0]);
+    /// # }
+    /// ```
+    pub fn truncate_or_pad<M: Arrayify<T>>(self, pad: T) -> Array<M, T>
+        where T: Clone
+    {
+        ArrayBuilder::collect(self.into_iter().chain(iter::repeat(pad)))
+    }
+
+    /// Transform every element of the `Array` with a fallible function, stopping at the first
+    /// error. On failure, the results computed so far and the elements which hadn't been reached
+    /// yet are all dropped; see `map_result_collect` to get the latter back instead.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert_eq!(*array!["1", "2"].try_map(str::parse::<i32>).unwrap(), [1, 2]);
+    /// assert!(array!["1", "x"].try_map(str::parse::<i32>).is_err());
+    /// # }
+    /// ```
+    pub fn try_map<U, E, F>(self, mut f: F) -> Result<Array<L, U>, E>
+        where L: Arrayify<U>,
+              F: FnMut(T) -> Result<U, E>
+    {
+        let mut builder = ArrayBuilder::new();
+        for x in self {
+            builder.push(f(x)?);
+        }
+        Ok(builder.finish())
+    }
+
+    /// Transform every element of the `Array` with a fallible function, stopping at the first
+    /// error. On failure, the error is returned along with all of the elements which hadn't been
+    /// reached yet, so that they can be recovered; the results computed so far are dropped.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array!["1", "x", "3", "4"];
+    /// let (_, rest) = array.map_result_collect(|s| s.parse::<i32>()).unwrap_err();
+    /// assert_eq!(rest, ["3", "4"]);
+    /// # }
+    /// ```
+    pub fn map_result_collect<U, E, F>(self, mut f: F) -> Result<Array<L, U>, (E, Vec<T>)>
+        where L: Arrayify<U>,
+              F: FnMut(T) -> Result<U, E>
+    {
+        let mut builder = ArrayBuilder::new();
+        let mut iter = self.into_iter();
+        while let Some(x) = iter.next() {
+            match f(x) {
+                Ok(y) => builder.push(y),
+                Err(e) => return Err((e, iter.collect())),
+            }
+        }
+        Ok(builder.finish())
+    }
+
+    /// Compute the maximum of every window of `W` consecutive elements, in `O(L)` time regardless
+    /// of `W`. This keeps a deque of the positions of those elements which could still turn out
+    /// to be the maximum of some window, so each element is only ever compared a constant number
+    /// of times.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let array = array![1i32, 3, 2, 5, 4, 1];
+    /// assert_eq!(*array.sliding_max::<U3>(), [3, 5, 5, 5]);
+    /// # }
+    /// ```
+    pub fn sliding_max<W>(&self) -> Array<Diff<L, Pred<W>>, T>
+        where T: Ord + Clone,
+              W: NatPred,
+              L: NatDiff<Pred<W>>,
+              Diff<L, Pred<W>>: Arrayify<T>
+    {
+        self.sliding_extremum::<W, _>(|back, next| back <= next)
+    }
+
+    /// Compute the minimum of every window of `W` consecutive elements, in `O(L)` time regardless
+    /// of `W`. See `sliding_max`.
+    pub fn sliding_min<W>(&self) -> Array<Diff<L, Pred<W>>, T>
+        where T: Ord + Clone,
+              W: NatPred,
+              L: NatDiff<Pred<W>>,
+              Diff<L, Pred<W>>: Arrayify<T>
+    {
+        self.sliding_extremum::<W, _>(|back, next| back >= next)
+    }
+
+    /// The monotonic deque algorithm behind `sliding_max` and `sliding_min`. `dominated(a, b)` is
+    /// true when an earlier element `a` can never be the extremum of a window which also
+    /// contains a later element `b`.
+    fn sliding_extremum<W, F>(&self, mut dominated: F) -> Array<Diff<L, Pred<W>>, T>
+        where T: Clone,
+              W: NatPred,
+              L: NatDiff<Pred<W>>,
+              Diff<L, Pred<W>>: Arrayify<T>,
+              F: FnMut(&T, &T) -> bool
+    {
+        let width = W::reify();
+        let mut builder = ArrayBuilder::new();
+        let mut candidates = VecDeque::with_capacity(width);
+        for (i, x) in self.iter().enumerate() {
+            while candidates.back().map_or(false, |&j| dominated(&self[j], x)) {
+                candidates.pop_back();
+            }
+            candidates.push_back(i);
+            if candidates[0] + width <= i {
+                candidates.pop_front();
+            }
+            if i + 1 >= width {
+                builder.push(self[candidates[0]].clone());
+            }
+        }
+        builder.finish()
+    }
+
+    /// Take elements off of the front of the `Array` for as long as they satisfy a predicate, but
+    /// no more than `K` of them. Since how many are taken is only known at runtime, the result is
+    /// split three ways: the elements which were taken, the rest of the first `K` elements, and
+    /// the last `L - K` elements - which can never be taken, and so keep their type-level length.
+    /// The `L: NatDiff<K>` bound is what ensures that `K <= L`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let array = array![1i32, 2, 3, 4, 5];
+    /// let (taken, rest, tail) = array.take_while_max::<U3, _>(|&x| x < 3);
+    /// assert_eq!(taken, [1, 2]);
+    /// assert_eq!(rest, [3]);
+    /// assert_eq!(*tail, [4, 5]);
+    /// # }
+    /// ```
+    pub fn take_while_max<K, F>(self, mut f: F) -> (Vec<T>, Vec<T>, Array<Diff<L, K>, T>)
+        where K: Nat,
+              L: NatDiff<K>,
+              Diff<L, K>: Arrayify<T>,
+              F: FnMut(&T) -> bool
+    {
+        let mut iter = self.into_iter();
+        let mut taken = Vec::new();
+        let mut rest = Vec::new();
+        for x in iter.by_ref().take(K::reify()) {
+            if rest.is_empty() && f(&x) {
+                taken.push(x);
+            } else {
+                rest.push(x);
+            }
+        }
+        (taken, rest, ArrayBuilder::collect(iter))
+    }
+
+    /// Build an `Array` out of every `K`th element of a longer `Array`, starting with the first.
+    /// This only works when the source is exactly `K` times as long as the result, and `K` is
+    /// nonzero; otherwise, `None` is returned. If the lengths are known to line up, see
+    /// `stride_collect_exact`, which checks this at compile time instead.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let source = array![0i32, 1, 2, 3, 4, 5];
+    /// let evens = Array::<U3, i32>::stride_collect::<_, 2>(source).unwrap();
+    /// assert_eq!(*evens, [0, 2, 4]);
+    /// # }
+    /// ```
+    pub fn stride_collect<S: Arrayify<T>, const K: usize>(source: Array<S, T>) -> Option<Self> {
+        if K == 0 || S::reify() != L::reify() * K {
+            return None;
+        }
+        Some(ArrayBuilder::collect(source.into_iter().step_by(K)))
+    }
+
+    /// Build an `Array` out of every `K`th element of an `Array` exactly `K` times as long,
+    /// starting with the first.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::{U2, U3};
+    ///
+    /// let source = array![0i32, 1, 2, 3, 4, 5];
+    /// let evens = Array::<U3, i32>::stride_collect_exact::<U2>(source);
+    /// assert_eq!(*evens, [0, 2, 4]);
+    /// # }
+    /// ```
+    pub fn stride_collect_exact<K>(source: Array<Product<L, K>, T>) -> Self
+        where K: NatPred,
+              L: NatProduct<K>,
+              Product<L, K>: Arrayify<T>
+    {
+        ArrayBuilder::collect(source.into_iter().step_by(K::reify()))
+    }
+
+    /// Sort the elements of the `Array` in place. Like the rest of this family of methods, this
+    /// just delegates to the slice method of the same name, and is stable.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let mut array = array![3i32, 1, 2];
+    /// array.sort();
+    /// assert_eq!(*array, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn sort(&mut self)
+        where T: Ord
+    {
+        self.deref_mut().sort()
+    }
+
+    /// Sort the elements of the `Array` in place with a comparison function. The sort is stable.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.deref_mut().sort_by(compare)
+    }
+
+    /// Sort the elements of the `Array` in place by a key extracted from each one. The sort is
+    /// stable.
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.deref_mut().sort_by_key(f)
+    }
+
+    /// Sort the elements of the `Array` in place, without preserving the order of equal elements.
+    pub fn sort_unstable(&mut self)
+        where T: Ord
+    {
+        self.deref_mut().sort_unstable()
+    }
+
+    /// Sort the elements of the `Array` in place with a comparison function, without preserving
+    /// the order of equal elements.
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        self.deref_mut().sort_unstable_by(compare)
+    }
+
+    /// Check whether the elements of the `Array` are in ascending order.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert!(array![1i32, 2, 2, 3].is_sorted());
+    /// assert!(!array![1i32, 3, 2].is_sorted());
+    /// # }
+    /// ```
+    pub fn is_sorted(&self) -> bool
+        where T: PartialOrd
+    {
+        self.windows(2).all(|w| w[0] <= w[1])
+    }
+
+    /// Check whether every pair of adjacent elements of the `Array` satisfies `compare`.
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut compare: F) -> bool {
+        self.windows(2).all(|w| compare(&w[0], &w[1]))
+    }
+
+    /// Binary search a sorted `Array` for `x`, exactly as the slice method of the same name does:
+    /// `Ok` with the index of a matching element, or `Err` with the index where `x` could be
+    /// inserted to keep the `Array` sorted.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+        where T: Ord
+    {
+        self.deref().binary_search(x)
+    }
+
+    /// Binary search an `Array` sorted by a key extracted from each element.
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(&self, b: &B, f: F)
+                                                          -> Result<usize, usize> {
+        self.deref().binary_search_by_key(b, f)
+    }
+
+    /// Binary search a sorted `Array` for `x`, keeping hold of the bounds on the result: an index
+    /// found is less than `L`, and so can index back into the `Array` with no bounds check, while
+    /// an insertion point is at most `L`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 3, 5, 7];
+    /// let found = array.binary_search_typed(&5).unwrap();
+    /// assert_eq!(array[found], 5);
+    /// assert_eq!(array.binary_search_typed(&8).unwrap_err().get(), 4);
+    /// # }
+    /// ```
+    pub fn binary_search_typed(&self, x: &T) -> Result<BoundedNat<L>, BoundedNat<Succ<L>>>
+        where T: Ord,
+              L: NatSucc
+    {
+        unsafe {
+            match self.binary_search(x) {
+                Ok(i) => Ok(BoundedNat::new_unchecked(i)),
+                Err(i) => Err(BoundedNat::new_unchecked(i)),
+            }
+        }
+    }
+
+    /// Sort the elements of the `Array`, pairing each one with the index it started out at - the
+    /// "argsort" of the `Array`, along with the sorted elements themselves. The sort is stable, so
+    /// equal elements keep their original relative order.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let sorted = array!['c', 'a', 'b'].into_sorted_with_indices();
+    /// assert_eq!(*sorted, [(1, 'a'), (2, 'b'), (0, 'c')]);
+    /// # }
+    /// ```
+    pub fn into_sorted_with_indices(self) -> Array<L, (usize, T)>
+        where T: Ord,
+              L: Arrayify<(usize, T)>
+    {
+        let mut indexed: Array<L, (usize, T)> =
+            ArrayBuilder::collect(self.into_iter().enumerate());
+        indexed.sort_by(|a, b| a.1.cmp(&b.1));
+        indexed
+    }
+
+    /// Collect clones of the elements of `source` at each of `indices`, so that element `i` of the
+    /// result is `source[indices[i]]`. Indices may repeat, and need not cover all of `source`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of `indices` is out of bounds for `source`. See `gather_checked`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    ///
+    /// let source = array!['a', 'b', 'c', 'd'];
+    /// let gathered = Array::gather(&source, array![3, 0, 0]);
+    /// assert_eq!(*gathered, ['d', 'a', 'a']);
+    /// # }
+    /// ```
+    pub fn gather<S: Arrayify<T>>(source: &Array<S, T>, indices: Array<L, usize>) -> Self
+        where T: Clone,
+              L: Arrayify<usize>
+    {
+        indices.map(|i| source[i].clone())
+    }
+
+    /// Collect clones of the elements of `source` at each of `indices`, or return `None` if any of
+    /// `indices` is out of bounds for `source`.
+    pub fn gather_checked<S: Arrayify<T>>(source: &Array<S, T>,
+                                          indices: Array<L, usize>)
+                                          -> Option<Self>
+        where T: Clone,
+              L: Arrayify<usize>
+    {
+        if indices.iter().all(|&i| i < S::reify()) {
+            Some(Array::gather(source, indices))
+        } else {
+            None
+        }
+    }
+
+    /// Move each element of the `Array` into `dest` at the corresponding position in `indices`,
+    /// dropping whatever was there before - the inverse of `gather`. If an index appears more than
+    /// once, the last element written to it wins.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of `indices` is out of bounds for `dest`. The elements written before the bad
+    /// index stay written.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let mut dest = array![0i32, 0, 0, 0];
+    /// array![42i32, 84].scatter(array![3, 1], &mut dest);
+    /// assert_eq!(*dest, [0, 84, 0, 42]);
+    /// # }
+    /// ```
+    pub fn scatter<D: Arrayify<T>>(self, indices: Array<L, usize>, dest: &mut Array<D, T>)
+        where L: Arrayify<usize>
+    {
+        for (x, i) in self.into_iter().zip(indices) {
+            dest[i] = x;
+        }
+    }
+
+    /// Split the `Array` into as many consecutive chunks of `W` elements as will fit, along with
+    /// the `L % W` elements left over at the end. Unlike splitting into exact chunks, this works
+    /// even when `W` does not divide `L`; the number of chunks and the length of the remainder
+    /// are both still known at compile time.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// let (chunks, rest) = array![1i32, 2, 3, 4, 5].into_chunks_remainder::<U2>();
+    /// assert_eq!(*chunks[0], [1, 2]);
+    /// assert_eq!(*chunks[1], [3, 4]);
+    /// assert_eq!(*rest, [5]);
+    /// # }
+    /// ```
+    pub fn into_chunks_remainder<W>(self)
+                                    -> (Array<Quotient<L, W>, Array<W, T>>,
+                                        Array<Remainder<L, W>, T>)
+        where W: Arrayify<T> + NatPred,
+              L: NatDivMod<W>,
+              Quotient<L, W>: Arrayify<Array<W, T>>,
+              Remainder<L, W>: Arrayify<T>
+    {
+        let mut iter = self.into_iter();
+        let chunks: Array<Quotient<L, W>, Array<W, T>> =
+            ArrayBuilder::collect((0..<Quotient<L, W> as Nat>::reify())
+                .map(|_| ArrayBuilder::collect(iter.by_ref())));
+        (chunks, ArrayBuilder::collect(iter))
+    }
+
+    /// Transform every element of the `Array` while threading an accumulator through from left to
+    /// right, returning the final accumulator along with the transformed `Array`. This is like a
+    /// scan, except that the accumulator need not be part of the output at all.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let (total, halves) = array![2i32, 4, 6].map_accumulate(0, |acc, x| (acc + x, x / 2));
+    /// assert_eq!(total, 12);
+    /// assert_eq!(*halves, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn map_accumulate<B, U, F>(self, init: B, mut f: F) -> (B, Array<L, U>)
+        where L: Arrayify<U>,
+              F: FnMut(B, T) -> (B, U)
+    {
+        let mut acc = Some(init);
+        let array = self.map(|x| {
+            let (next, y) = f(acc.take().unwrap(), x);
+            acc = Some(next);
+            y
+        });
+        (acc.unwrap(), array)
+    }
+
+    /// Combine two `Array`s of the same length element by element with `f`, without building an
+    /// intermediate `Array` of pairs as `zip` followed by `map` would.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let sums = array![1i32, 2, 3].zip_with(array![10, 20, 30], |a, b| a + b);
+    /// assert_eq!(*sums, [11, 22, 33]);
+    /// # }
+    /// ```
+    pub fn zip_with<U, V, F>(self, other: Array<L, U>, mut f: F) -> Array<L, V>
+        where L: Arrayify<U> + Arrayify<V>,
+              F: FnMut(T, U) -> V
+    {
+        ArrayBuilder::collect(self.into_iter().zip(other).map(|(a, b)| f(a, b)))
+    }
+
+    /// Compute the inclusive left scan of the `Array`: element `i` of the result is the
+    /// accumulator after folding `f` over elements `0` through `i`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let maxima = array![3i32, 1, 4, 1, 5].scan(i32::min_value(), |acc, x| acc.max(x));
+    /// assert_eq!(*maxima, [3, 3, 4, 4, 5]);
+    /// # }
+    /// ```
+    pub fn scan<B, F>(self, init: B, mut f: F) -> Array<L, B>
+        where B: Clone,
+              L: Arrayify<B>,
+              F: FnMut(B, T) -> B
+    {
+        self.map_accumulate(init, |acc, x| {
+                let next = f(acc, x);
+                (next.clone(), next)
+            })
+            .1
+    }
+
+    /// Compute the exclusive left scan of the `Array`: element `i` of the result is the
+    /// accumulator after folding `f` over elements `0` up to but not including `i`, so the first
+    /// element is always `init`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let offsets = array![3usize, 1, 4].scan_exclusive(0, |acc, x| acc + x);
+    /// assert_eq!(*offsets, [0, 3, 4]);
+    /// # }
+    /// ```
+    pub fn scan_exclusive<B, F>(self, init: B, mut f: F) -> Array<L, B>
+        where B: Clone,
+              L: Arrayify<B>,
+              F: FnMut(B, T) -> B
+    {
+        self.map_accumulate(init, |acc, x| {
+                let prev = acc.clone();
+                (f(acc, x), prev)
+            })
+            .1
+    }
+
+    /// Zip three `Array`s of the same length together into an `Array` of triples. This goes
+    /// through all three at once, rather than building an intermediate `Array` of pairs as
+    /// `a.zip(b).zip(c)` would; each input keeps track of its own progress, so if anything
+    /// goes wrong partway, the elements not yet moved out of any of them are still dropped.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let zipped = array![1i32, 2].zip3(array!['a', 'b'], array![true, false]);
+    /// assert_eq!(*zipped, [(1, 'a', true), (2, 'b', false)]);
+    /// # }
+    /// ```
+    pub fn zip3<U, V>(self, b: Array<L, U>, c: Array<L, V>) -> Array<L, (T, U, V)>
+        where L: Arrayify<U> + Arrayify<V> + Arrayify<(T, U, V)>
+    {
+        ArrayBuilder::collect(self.into_iter().zip(b).zip(c).map(|((t, u), v)| (t, u, v)))
+    }
+
+    /// Bisect an even-length `Array` into its first and second halves, without copying anything
+    /// but the elements themselves. This is meant for divide-and-conquer algorithms which recurse
+    /// on both halves; when the halves are really two equal-length `Array`s which were packed
+    /// together, `into_pair` says so more clearly.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let (front, back) = array![1i32, 2, 3, 4].half_split();
+    /// assert_eq!(*front, [1, 2]);
+    /// assert_eq!(*back, [3, 4]);
+    /// # }
+    /// ```
+    pub fn half_split(self) -> (Array<Quotient<L, U2>, T>, Array<Quotient<L, U2>, T>)
+        where L: IsEven,
+              Quotient<L, U2>: Arrayify<T>
+    {
+        let half = L::reify() / 2;
+        let source = mem::ManuallyDrop::new(self);
+        unsafe {
+            let ptr = source.as_ptr();
+            (ptr::read(ptr as *const Array<Quotient<L, U2>, T>),
+             ptr::read(ptr.offset(half as isize) as *const Array<Quotient<L, U2>, T>))
+        }
+    }
+
+    /// Split an even-length `Array` into a pair of `Array`s of half the length, the first holding
+    /// the first half of the elements and the second the rest. This does exactly the same thing
+    /// as `half_split`, but reads better when the `Array` is a pair packed together - for
+    /// example, the real and imaginary parts of some vector, stored one after the other.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let (re, im) = array![1.0f64, 0.0, 0.5, 2.0].into_pair();
+    /// assert_eq!(*re, [1.0, 0.0]);
+    /// assert_eq!(*im, [0.5, 2.0]);
+    /// # }
+    /// ```
+    pub fn into_pair(self) -> (Array<Quotient<L, U2>, T>, Array<Quotient<L, U2>, T>)
+        where L: IsEven,
+              Quotient<L, U2>: Arrayify<T>
+    {
+        self.half_split()
+    }
+
+    /// Build an `Array` out of the first `L` elements of a `Vec`, dropping any others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Vec` has fewer than `L` elements.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// let array = Array::<U2, i32>::from_vec_truncating(vec![1, 2, 3]);
+    /// assert_eq!(*array, [1, 2]);
+    /// # }
+    /// ```
+    pub fn from_vec_truncating(v: Vec<T>) -> Self {
+        ArrayBuilder::collect(v)
+    }
+
+    /// Build an `Array` out of the first `L` elements of a `Vec`, dropping any others, and filling
+    /// out the end with clones of `pad` if the `Vec` is too short.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U4;
+    ///
+    /// let array = Array::<U4, i32>::from_vec_padding(vec![1, 2], 0);
+    /// assert_eq!(*array, [1, 2, 0, 0]);
+    /// # }
+    /// ```
+    pub fn from_vec_padding(v: Vec<T>, pad: T) -> Self
+        where T: Clone
+    {
+        ArrayBuilder::collect(v.into_iter().chain(iter::repeat(pad)))
+    }
+
+    /// Replace the element at `index` with `value`, dropping the old element, and hand back the
+    /// `Array`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds. See `with_value_at_nat`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2, 3].with_value_at(1, 42);
+    /// assert_eq!(*array, [1, 42, 3]);
+    /// # }
+    /// ```
+    pub fn with_value_at(mut self, index: usize, value: T) -> Self {
+        self[index] = value;
+        self
+    }
+
+    /// Replace the element at the type-level index `I` with `value`, dropping the old element, and
+    /// hand back the `Array`. The `I: NatLt<L>` bound ensures the index is in bounds, so no
+    /// runtime check is needed.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// let array = array![1i32, 2, 3].with_value_at_nat::<U2>(42);
+    /// assert_eq!(*array, [1, 2, 42]);
+    /// # }
+    /// ```
+    pub fn with_value_at_nat<I: NatLt<L>>(mut self, value: T) -> Self {
+        *self.get_nat_mut::<I>() = value;
+        self
+    }
+
+    /// The offset in bytes from the start of an `Array<L, T>` to its element at the type-level
+    /// index `I`. Since the elements of an `Array` are laid out contiguously with no padding in
+    /// between, just as in a slice, this is always `I * size_of::<T>()`.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::{U3, U8};
+    ///
+    /// assert_eq!(Array::<U8, u32>::element_offset::<U3>(), 12);
+    /// # }
+    /// ```
+    pub fn element_offset<I: NatLt<L>>() -> usize {
+        I::reify() * mem::size_of::<T>()
+    }
+
+    /// Transform every element of an even-length `Array`, applying `f1` to the elements at even
+    /// positions and `f2` to those at odd positions - for example, the left and right channels of
+    /// interleaved stereo samples.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2, 3, 4].map_pair(|x| x * 10, |x| -x);
+    /// assert_eq!(*array, [10, -2, 30, -4]);
+    /// # }
+    /// ```
+    pub fn map_pair<U, F1, F2>(self, mut f1: F1, mut f2: F2) -> Array<L, U>
+        where L: IsEven + Arrayify<U>,
+              F1: FnMut(T) -> U,
+              F2: FnMut(T) -> U
+    {
+        let mut odd = false;
+        self.map(|x| {
+            odd = !odd;
+            if odd { f1(x) } else { f2(x) }
+        })
+    }
+
+    /// Check whether every element of the `Array` is different from every other.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert!(array![3i32, 1, 4].all_distinct());
+    /// assert!(!array![3i32, 1, 4, 1].all_distinct());
+    /// # }
+    /// ```
+    pub fn all_distinct(&self) -> bool
+        where T: Eq + Hash
+    {
+        let mut seen = HashSet::with_capacity(L::reify());
+        self.iter().all(|x| seen.insert(x))
+    }
+
+    /// Check whether every element of the `Array` is different from every other, for element
+    /// types which can't be hashed. This compares every pair of elements directly, taking
+    /// `O(L^2)` time but no allocation, which is the better trade for small `L`.
+    pub fn all_distinct_ord(&self) -> bool
+        where T: Ord
+    {
+        self.iter().enumerate().all(|(i, x)| self[i + 1..].iter().all(|y| x != y))
+    }
+
+    /// Combine two `Array`s of the same type element by element with a function `f` of two
+    /// arguments which is meant to be symmetric, so that `f(a, b) == f(b, a)`. Nothing checks
+    /// this, and the elements of `a` are always passed first; the name only records the intent.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    ///
+    /// let products = Array::symmetric_apply(array![1i32, 2, 3], array![4, 5, 6], |a, b| a * b);
+    /// assert_eq!(*products, [4, 10, 18]);
+    /// # }
+    /// ```
+    pub fn symmetric_apply<U, F>(a: Self, b: Self, f: F) -> Array<L, U>
+        where L: Arrayify<U>,
+              F: FnMut(T, T) -> U
+    {
+        a.zip_with(b, f)
+    }
+
+    /// Compute the distance between each pair of corresponding elements of two `Array`s, under a
+    /// symmetric `metric`. See `symmetric_apply`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let a = array![1i32, 5, -3];
+    /// let b = array![4i32, 2, 3];
+    /// assert_eq!(*a.symmetric_distance(b, |x, y| (x - y).abs()), [3, 3, 6]);
+    /// # }
+    /// ```
+    pub fn symmetric_distance<F>(self, other: Self, metric: F) -> Self
+        where F: FnMut(T, T) -> T
+    {
+        Array::symmetric_apply(self, other, metric)
+    }
+
+    /// View the first `L` elements of a slice as an `Array<L, T>`, handing back the rest of the
+    /// slice along with it, or return `None` if the slice is too short. Nothing is copied, which
+    /// makes this handy for picking fixed-size records off of the front of a buffer.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// let words = [1u32, 2, 3];
+    /// let (header, rest) = Array::<U2, u32>::init_from_slice_checked(&words).unwrap();
+    /// assert_eq!(**header, [1, 2]);
+    /// assert_eq!(rest, [3]);
+    /// # }
+    /// ```
+    pub fn init_from_slice_checked<'a>(s: &'a [T]) -> Option<(&'a Array<L, T>, &'a [T])> {
+        if s.len() >= L::reify() {
+            let (init, rest) = s.split_at(L::reify());
+            Some((unsafe { &*(init.as_ptr() as *const Array<L, T>) }, rest))
+        } else {
+            None
+        }
+    }
+
+    /// Mutably view the first `L` elements of a slice as an `Array<L, T>`, handing back the rest
+    /// of the slice along with it, or return `None` if the slice is too short.
+    pub fn init_from_slice_mut_checked<'a>(s: &'a mut [T])
+                                           -> Option<(&'a mut Array<L, T>, &'a mut [T])> {
+        if s.len() >= L::reify() {
+            let (init, rest) = s.split_at_mut(L::reify());
+            Some((unsafe { &mut *(init.as_mut_ptr() as *mut Array<L, T>) }, rest))
+        } else {
+            None
+        }
+    }
+
+    /// Treat the `Array` as a matrix with `M` columns, stored row by row, and split it up into its
+    /// rows. This is only a change of type: the elements stay exactly where they are.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let rows = array![1i32, 2, 3, 4, 5, 6].to_row_major::<U3>();
+    /// assert_eq!(*rows[0], [1, 2, 3]);
+    /// assert_eq!(*rows[1], [4, 5, 6]);
+    /// # }
+    /// ```
+    pub fn to_row_major<M>(self) -> Array<Quotient<L, M>, Array<M, T>>
+        where M: NatPred + Arrayify<T>,
+              L: NatDivMod<M, Rem = Term>,
+              Quotient<L, M>: Arrayify<Array<M, T>>
+    {
+        debug_assert_eq!(mem::size_of::<Self>(),
+                         mem::size_of::<Array<Quotient<L, M>, Array<M, T>>>());
+
+        let source = mem::ManuallyDrop::new(self);
+        unsafe { ptr::read(source.as_ptr() as *const Array<Quotient<L, M>, Array<M, T>>) }
+    }
+
+    /// Treat the `Array` as a matrix with `M` columns, stored row by row, and split it up into its
+    /// columns. Unlike `to_row_major`, this has to move the elements around.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let columns = array![1i32, 2, 3, 4, 5, 6].to_column_major::<U3>();
+    /// assert_eq!(*columns[0], [1, 4]);
+    /// assert_eq!(*columns[2], [3, 6]);
+    /// # }
+    /// ```
+    pub fn to_column_major<M>(self) -> Array<M, Array<Quotient<L, M>, T>>
+        where M: NatPred + Arrayify<Array<Quotient<L, M>, T>>,
+              L: NatDivMod<M, Rem = Term>,
+              Quotient<L, M>: Arrayify<T>
+    {
+        let rows = <Quotient<L, M> as Nat>::reify();
+        let columns = M::reify();
+        let source = mem::ManuallyDrop::new(self);
+        ArrayBuilder::collect((0..columns).map(|j| {
+            ArrayBuilder::collect((0..rows)
+                .map(|i| unsafe { ptr::read(&source[i * columns + j]) }))
+        }))
+    }
+
+    /// Borrow the element at the type-level index `I`. The `I: NatLt<L>` bound ensures the index
+    /// is in bounds, so no runtime check is needed.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U2;
+    ///
+    /// assert_eq!(*array![42i32, 84, 126].get_nat::<U2>(), 126);
+    /// # }
+    /// ```
+    pub fn get_nat<I: NatLt<L>>(&self) -> &T {
+        // SAFETY: `I: NatLt<L>` proves that `I < L`.
+        unsafe { self.get_unchecked(I::reify()) }
+    }
+
+    /// Mutably borrow the element at the type-level index `I`.
+    pub fn get_nat_mut<I: NatLt<L>>(&mut self) -> &mut T {
+        // SAFETY: `I: NatLt<L>` proves that `I < L`.
+        unsafe { self.get_unchecked_mut(I::reify()) }
+    }
+
+    /// Borrow the element at the type-level index `I`, without any bounds check.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `I < L`. The `I: NatLt<L>` bound already proves this at
+    /// compile time, so there is never a reason to prefer this over `get_nat`; it exists for
+    /// symmetry with `get_unchecked` on slices.
+    pub unsafe fn get_unchecked_nat<I: NatLt<L>>(&self) -> &T {
+        self.get_unchecked(I::reify())
+    }
+
+    /// Compute an `Array` of results, one for each position, from a function handed both the
+    /// position and the whole of `self`. This is for when each output depends on several of the
+    /// inputs at once - typically neighbors, reached with wrapping index arithmetic. The `Array`
+    /// is only borrowed, since no element can be moved out while `f` can still see all of them.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2, 3, 4];
+    /// let sums = array.cycle_map(|i, a| a[(i + 3) % 4] + a[i] + a[(i + 1) % 4]);
+    /// assert_eq!(*sums, [7, 6, 9, 8]);
+    /// # }
+    /// ```
+    pub fn cycle_map<U, F>(&self, mut f: F) -> Array<L, U>
+        where L: Arrayify<U>,
+              F: FnMut(usize, &Self) -> U
+    {
+        ArrayBuilder::collect((0..L::reify()).map(|i| f(i, self)))
+    }
+
+    /// Combine each element with the one after it, wrapping around at the end, so that element
+    /// `i` of the result is `f(a[i], a[(i + 1) % L])`. Every element is passed to `f` twice, so
+    /// one clone of each is made.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let steps = array![1i32, 3, 6, 10].cycle_shift_map(|a, b| b - a);
+    /// assert_eq!(*steps, [2, 3, 4, -9]);
+    /// # }
+    /// ```
+    pub fn cycle_shift_map<U, F>(self, mut f: F) -> Array<L, U>
+        where T: Clone,
+              L: Arrayify<U>,
+              F: FnMut(T, T) -> U
+    {
+        let mut first = self.first().cloned();
+        let mut builder = ArrayBuilder::new();
+        let mut iter = self.into_iter().peekable();
+        while let Some(x) = iter.next() {
+            let next = match iter.peek() {
+                Some(next) => next.clone(),
+                None => first.take().unwrap(),
+            };
+            builder.push(f(x, next));
+        }
+        builder.finish()
+    }
+
+    /// Reduce the `Array` to a single value by combining adjacent pairs as a balanced tree: first
+    /// every even-odd pair of elements, then every pair of those results, and so on. Since the
+    /// pairs at each level always come out even, `L` must be a power of two.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array!["a", "b", "c", "d"].map(String::from);
+    /// assert_eq!(array.fold_pair(|a, b| format!("({}{})", a, b)), "((ab)(cd))");
+    /// # }
+    /// ```
+    pub fn fold_pair<F: FnMut(T, T) -> T>(self, mut f: F) -> T
+        where L: IsPowerOfTwo
+    {
+        // The reduction happens in place: each result is written back over the front of the
+        // level being reduced, which has always been read past by then. While a level is under
+        // way, the live elements are the results `0..written` and the inputs `read..len`, and
+        // those are what get dropped should `f` panic.
+        struct Level<T> {
+            ptr: *mut T,
+            written: usize,
+            read: usize,
+            len: usize,
+        }
+
+        impl<T> Drop for Level<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    let unread = self.ptr.offset(self.read as isize);
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, self.written));
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(unread, self.len - self.read));
+                }
+            }
+        }
+
+        let mut data = mem::ManuallyDrop::new(self);
+        let mut level = Level {
+            ptr: data.as_mut_ptr(),
+            written: 0,
+            read: 0,
+            len: L::reify(),
+        };
+        unsafe {
+            while level.len > 1 {
+                level.written = 0;
+                level.read = 0;
+                while level.read < level.len {
+                    let a = ptr::read(level.ptr.offset(level.read as isize));
+                    let b = ptr::read(level.ptr.offset(level.read as isize + 1));
+                    level.read += 2;
+                    let combined = f(a, b);
+                    ptr::write(level.ptr.offset(level.written as isize), combined);
+                    level.written += 1;
+                }
+                level.len = level.written;
+            }
+            let result = ptr::read(level.ptr);
+            mem::forget(level);
+            result
+        }
+    }
+
+    /// Copy the elements of the `Array` out into a plain `[T; N]`, if `N` is the same as `L`, for
+    /// handing off to code which works with built-in arrays. Until there is a way to require
+    /// `N == L` in the type, the check has to happen at runtime.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1.0f32, 2.0, 3.0];
+    /// assert_eq!(array.to_slice_copy::<3>(), Some([1.0, 2.0, 3.0]));
+    /// assert_eq!(array.to_slice_copy::<4>(), None);
+    /// # }
+    /// ```
+    pub fn to_slice_copy<const N: usize>(&self) -> Option<[T; N]>
+        where T: Copy
+    {
+        <[T; N]>::try_from(&self[..]).ok()
+    }
+
+    /// Compare two `Array`s element by element, sorting each pair into an `Array` of the smaller
+    /// elements and an `Array` of the larger ones - in a single pass, with no clones. On ties,
+    /// the element from `self` goes to the minimums, just as with `cmp::min` and `cmp::max`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let (lo, hi) = array![1i32, 5, 3].zip_min_max(array![4, 2, 3]);
+    /// assert_eq!(*lo, [1, 2, 3]);
+    /// assert_eq!(*hi, [4, 5, 3]);
+    /// # }
+    /// ```
+    pub fn zip_min_max(self, other: Self) -> (Self, Self)
+        where T: Ord
+    {
+        let mut mins = ArrayBuilder::new();
+        let mut maxes = ArrayBuilder::new();
+        for (a, b) in self.into_iter().zip(other) {
+            if b < a {
+                mins.push(b);
+                maxes.push(a);
+            } else {
+                mins.push(a);
+                maxes.push(b);
+            }
+        }
+        (mins.finish(), maxes.finish())
+    }
+
+    /// Reinterpret the elements of the `Array` as some other type `U` of the same size, such as
+    /// `i32` as `u32`. It is a compile-time error for `U` to be a different size than `T`, or to
+    /// need a stricter alignment, so this catches the mistakes which a bare `mem::transmute`
+    /// between arrays would let through.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `self` must be a valid value of `U`, just as with `mem::transmute`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![-1i32, 1];
+    /// let unsigned = unsafe { array.checked_transmute::<u32>() };
+    /// assert_eq!(*unsigned, [0xffffffff, 1]);
+    /// # }
+    /// ```
+    pub unsafe fn checked_transmute<U>(self) -> Array<L, U>
+        where L: Arrayify<U>
+    {
+        use std::marker::PhantomData;
+
+        // The assertions live in an associated constant, which the compiler evaluates - and so
+        // fails the build over - for every `T` and `U` that `checked_transmute` is used with.
+        struct AssertSameLayout<T, U>(PhantomData<(T, U)>);
+
+        impl<T, U> AssertSameLayout<T, U> {
+            const OK: () = {
+                assert!(mem::size_of::<T>() == mem::size_of::<U>(),
+                        "checked_transmute between types of different sizes");
+                assert!(mem::align_of::<T>() >= mem::align_of::<U>(),
+                        "checked_transmute to a type with stricter alignment");
+            };
+        }
+
+        let _ = AssertSameLayout::<T, U>::OK;
+
+        let source = mem::ManuallyDrop::new(self);
+        ptr::read(&*source as *const Self as *const Array<L, U>)
+    }
+
+    /// Overwrite the elements of the `Array` with copies of those of `src`. Since the two `Array`s
+    /// are the same length by construction, this is a plain `memcpy`, with neither the length
+    /// check of `copy_from_slice` nor the per-element calls of `clone_from`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let mut array = array![0u8, 0, 0];
+    /// array.copy_from_array(&array![1, 2, 3]);
+    /// assert_eq!(*array, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn copy_from_array(&mut self, src: &Array<L, T>)
+        where T: Copy
+    {
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr(), L::reify());
+        }
+    }
+
+    /// Reduce every window of `W` consecutive elements with `f`, from left to right, producing an
+    /// `Array` of the `L - W + 1` results. This makes no assumptions about `f`, so each window is
+    /// reduced separately, cloning its elements, in `O(L * W)` time overall; when `f` is
+    /// associative, `windows_reduce_associative` does the same in `O(L)`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let sums = array![1i32, 2, 3, 4, 5].windows_reduce::<U3, _>(|a, b| a + b);
+    /// assert_eq!(*sums, [6, 9, 12]);
+    /// # }
+    /// ```
+    pub fn windows_reduce<W, F>(&self, mut f: F) -> Array<Diff<L, Pred<W>>, T>
+        where T: Clone,
+              W: NatPred,
+              L: NatDiff<Pred<W>>,
+              Diff<L, Pred<W>>: Arrayify<T>,
+              F: FnMut(T, T) -> T
+    {
+        ArrayBuilder::collect(self.windows(W::reify()).map(|window| {
+            let mut iter = window.iter().cloned();
+            let first = iter.next().unwrap();
+            iter.fold(first, |a, b| f(a, b))
+        }))
+    }
+
+    /// Reduce every window of `W` consecutive elements with the associative operation `Op`, in
+    /// `O(L)` time regardless of `W`. The `Array` is cut into blocks of `W` elements, and the
+    /// reductions of every prefix and every suffix of each block are computed; since a window
+    /// always spans the end of one block and the start of the next, it can then be reduced with a
+    /// single further `combine`. Nothing is allocated along the way: the suffixes are built up in
+    /// the result itself, and each prefix is carried forward from the one before.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::reduce::Min;
+    /// use tll_array::tll::ternary::U3;
+    ///
+    /// let array = array![4i32, 2, 5, 3, 1, 6];
+    /// assert_eq!(*array.windows_reduce_associative::<U3, Min>(), [2, 2, 1, 1]);
+    /// # }
+    /// ```
+    pub fn windows_reduce_associative<W, Op>(&self) -> Array<Diff<L, Pred<W>>, T>
+        where T: Clone,
+              W: NatPred,
+              L: NatDiff<Pred<W>>,
+              Diff<L, Pred<W>>: Arrayify<T>,
+              Op: AssociativeOp<T>
+    {
+        let width = W::reify();
+
+        // Every window starting in a block is first given the suffix of the block from its start.
+        // All but the first window in the block then have the prefix of the next block up to their
+        // end combined in; the first needs nothing more, since it is the whole block.
+        let mut reduced: Array<Diff<L, Pred<W>>, T> = ArrayBuilder::collect(self.iter().cloned());
+        let count = reduced.len();
+        for start in (0..count).step_by(width) {
+            let mut suffix = self[start + width - 1].clone();
+            for i in (start..start + width - 1).rev() {
+                suffix = Op::combine(self[i].clone(), suffix);
+                if i < count {
+                    reduced[i] = suffix.clone();
+                }
+            }
+
+            let mut prefix = None;
+            for i in start + 1..(start + width).min(count) {
+                let last = self[i + width - 1].clone();
+                let combined = match prefix.take() {
+                    Some(prefix) => Op::combine(prefix, last),
+                    None => last,
+                };
+                reduced[i] = Op::combine(reduced[i].clone(), combined.clone());
+                prefix = Some(combined);
+            }
+        }
+        reduced
+    }
+
+    /// Collect an `Array` out of an iterator which reports its exact length at runtime, such as
+    /// the iterator of a `Vec` or a `Range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator's reported length is not `L`, or if it runs out early anyway. Either
+    /// way, the message gives both the expected and the actual length.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U4;
+    ///
+    /// let squares = Array::<U4, u32>::from_exact_iter((0..4).map(|x| x * x));
+    /// assert_eq!(*squares, [0, 1, 4, 9]);
+    /// # }
+    /// ```
+    pub fn from_exact_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item = T>,
+              I::IntoIter: ExactSizeIterator
+    {
+        let iter = iter.into_iter();
+        assert!(iter.len() == L::reify(),
+                "expected an iterator of length {}, but it has length {}",
+                L::reify(),
+                iter.len());
+        ArrayBuilder::collect(iter)
+    }
+
+    /// Remove the elements from `Lo` up to (but not including) `Hi` from the `Array`, returning
+    /// them as a `Vec` along with an `Array` of the `L - (Hi - Lo)` elements which are left. The
+    /// bounds ensure that `Lo <= Hi <= L`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::{U1, U3};
+    ///
+    /// let (rest, drained) = array![1i32, 2, 3, 4].drain_range::<U1, U3>();
+    /// assert_eq!(*rest, [1, 4]);
+    /// assert_eq!(drained, [2, 3]);
+    /// # }
+    /// ```
+    pub fn drain_range<Lo, Hi>(self) -> (Array<Diff<L, Diff<Hi, Lo>>, T>, Vec<T>)
+        where Lo: Nat,
+              Hi: NatDiff<Lo> + NatLe<L>,
+              L: NatDiff<Diff<Hi, Lo>>,
+              Diff<L, Diff<Hi, Lo>>: Arrayify<T>
+    {
+        let mut builder = ArrayBuilder::new();
+        let mut iter = self.into_iter();
+        for x in iter.by_ref().take(Lo::reify()) {
+            builder.push(x);
+        }
+        let drained = iter.by_ref().take(Hi::reify() - Lo::reify()).collect();
+        for x in iter {
+            builder.push(x);
+        }
+        (builder.finish(), drained)
+    }
+
+    /// Insert `value` at the type-level position `I`, shifting the elements from there on one
+    /// place to the right, so that the result is one element longer. `I` may be anything from
+    /// zero (to prepend) up to `L` itself (to append).
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// use tll_array::tll::ternary::U1;
+    ///
+    /// let array = array![1i32, 3].insert_at::<U1>(2);
+    /// assert_eq!(*array, [1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn insert_at<I: NatLe<L>>(self, value: T) -> Array<Succ<L>, T>
+        where L: NatSucc,
+              Succ<L>: Arrayify<T>
+    {
+        let mut builder = ArrayBuilder::new();
+        let mut iter = self.into_iter();
+        for x in iter.by_ref().take(I::reify()) {
+            builder.push(x);
+        }
+        builder.push(value);
+        for x in iter {
+            builder.push(x);
+        }
+        builder.finish()
+    }
+
+    /// Borrow every element of the `Array` at once, producing an `Array` of references of the same
+    /// length. Unlike a slice iterator, the result is itself an `Array`, so it can be handed to
+    /// anything expecting an `Array<L, _>`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![42i32, 84, 126];
+    /// let refs = array.to_array_of_refs();
+    /// assert_eq!(*refs[2], 126);
+    /// # }
+    /// ```
+    pub fn to_array_of_refs<'a>(&'a self) -> Array<L, &'a T>
+        where L: Arrayify<&'a T>
+    {
+        ArrayBuilder::collect(self.iter())
+    }
+
+    /// Mutably borrow every element of the `Array` at once, producing an `Array` of mutable
+    /// references of the same length.
+    pub fn to_array_of_mut_refs<'a>(&'a mut self) -> Array<L, &'a mut T>
+        where L: Arrayify<&'a mut T>
+    {
+        ArrayBuilder::collect(self.iter_mut())
+    }
+
+    /// Borrow every element of the `Array` at once. This is another name for `to_array_of_refs`,
+    /// following the `as_` convention for cheap borrowing conversions.
+    pub fn as_ref_array<'a>(&'a self) -> Array<L, &'a T>
+        where L: Arrayify<&'a T>
+    {
+        self.to_array_of_refs()
+    }
+}
+
+
+impl<L: Arrayify<T> + NatValue, T> Array<L, T> {
+    /// The length of the `Array`, as a constant rather than through `L::reify()`, so that it can
+    /// be used in constant expressions.
+    ///
+    /// ```
+    /// # extern crate tll_array; fn main() {
+    /// use tll_array::Array;
+    /// use tll_array::tll::ternary::U8;
+    ///
+    /// const BYTES: usize = Array::<U8, u32>::LENGTH * 4;
+    /// assert_eq!(BYTES, 32);
+    /// # }
+    /// ```
+    pub const LENGTH: usize = L::VALUE;
+}
+
+
+impl<L: Arrayify<(A, B)> + Arrayify<A> + Arrayify<B>, A, B> Array<L, (A, B)> {
+    /// Split an `Array` of pairs into an `Array` of the first elements and an `Array` of the
+    /// second elements - the inverse of `zip`. Each pair is moved apart, with no clones; both
+    /// halves are staged in their own `ArrayBuilder`, so even an unexpected panic partway through
+    /// would drop exactly the elements moved so far.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let (numbers, letters) = array![(1i32, 'a'), (2, 'b')].unzip();
+    /// assert_eq!(*numbers, [1, 2]);
+    /// assert_eq!(*letters, ['a', 'b']);
+    /// # }
+    /// ```
+    pub fn unzip(self) -> (Array<L, A>, Array<L, B>) {
+        let mut firsts = ArrayBuilder::new();
+        let mut seconds = ArrayBuilder::new();
+        for (a, b) in self {
+            firsts.push(a);
+            seconds.push(b);
+        }
+        (firsts.finish(), seconds.finish())
+    }
+}
+
+
+impl<L: Arrayify<Array<M, T>>, M: Arrayify<T>, T> Array<L, Array<M, T>> {
+    /// Flatten an `Array` of `Array`s into a single `Array` of all of their elements, in order.
+    /// Since the inner `Array`s are laid out one after another with no padding in between, this
+    /// is only a change of type, and nothing is moved.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let nested = array![array![1i32, 2], array![3, 4], array![5, 6]];
+    /// assert_eq!(*nested.flatten(), [1, 2, 3, 4, 5, 6]);
+    /// # }
+    /// ```
+    pub fn flatten(self) -> Array<Product<L, M>, T>
+        where L: NatProduct<M>,
+              Product<L, M>: Arrayify<T>
+    {
+        debug_assert_eq!(mem::size_of::<Self>(), mem::size_of::<Array<Product<L, M>, T>>());
+
+        let source = mem::ManuallyDrop::new(self);
+        unsafe { ptr::read(&*source as *const Self as *const Array<Product<L, M>, T>) }
+    }
+}
+
+
+/// The by-value iterator over an `Array`. The elements still to come are exactly those in
+/// `pos..pos_back`; everything outside that range has either been moved out already or was never
+/// written in the first place, which is why the backing `Array` is kept as `MaybeUninit` rather
+/// than claiming to be fully initialized.
+pub struct ArrayIter<L: Arrayify<T>, T> {
+    data: mem::MaybeUninit<Array<L, T>>,
+    pos: usize,
+    pos_back: usize,
+}
+
+impl<L: Arrayify<T>, T> ArrayIter<L, T> {
+    fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    fn remaining(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.as_ptr().offset(self.pos as isize),
+                                  self.pos_back - self.pos)
+        }
+    }
+}
+
+impl<L: Arrayify<T>, T> Drop for ArrayIter<L, T> {
+    fn drop(&mut self) {
+        unsafe {
+            let data = self.data.as_mut_ptr() as *mut T;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(data.offset(self.pos as isize),
+                                                             self.pos_back - self.pos));
+        }
+    }
+}
+
+impl<L: Arrayify<T>, T> Iterator for ArrayIter<L, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.pos < self.pos_back {
+            let next = unsafe { ptr::read(self.as_ptr().offset(self.pos as isize)) };
+            self.pos += 1;
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.pos_back - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<L: Arrayify<T>, T> DoubleEndedIterator for ArrayIter<L, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.pos < self.pos_back {
+            self.pos_back -= 1;
+            Some(unsafe { ptr::read(self.as_ptr().offset(self.pos_back as isize)) })
+        } else {
+            None
+        }
+    }
+}
+
+/// Cloning an `ArrayIter` clones only the elements it has left, placing each one at the same
+/// position in the new iterator's backing `Array` that it holds in the old one. The new iterator
+/// starts out empty, with `pos_back` advanced past each element as it is written, so that should
+/// a `clone` panic partway through, exactly the elements cloned so far get dropped.
+impl<L: Arrayify<T>, T: Clone> Clone for ArrayIter<L, T> {
+    fn clone(&self) -> Self {
+        let mut cloned = ArrayIter {
+            data: mem::MaybeUninit::uninit(),
+            pos: self.pos,
+            pos_back: self.pos,
+        };
+        for x in self.remaining() {
+            unsafe {
+                let dst = cloned.data.as_mut_ptr() as *mut T;
+                ptr::write(dst.offset(cloned.pos_back as isize), x.clone());
+            }
+            cloned.pos_back += 1;
+        }
+        cloned
+    }
+}
+
+impl<L: Arrayify<T>, T: fmt::Debug> fmt::Debug for ArrayIter<L, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ArrayIter")
+            .field("remaining", &self.remaining())
+            .field("pos", &self.pos)
+            .field("pos_back", &self.pos_back)
+            .finish()
+    }
+}
+
+impl<L: Arrayify<T>, T> IntoIterator for Array<L, T> {
+    type IntoIter = ArrayIter<L, T>;
+    type Item = T;
+
+    fn into_iter(self) -> ArrayIter<L, T> {
+        ArrayIter {
+            data: mem::MaybeUninit::new(self),
+            pos: 0,
+            pos_back: L::reify(),
+        }
+    }
+}
+
+impl<'a, L: Arrayify<T>, T> IntoIterator for &'a Array<L, T> {
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, L: Arrayify<T>, T> IntoIterator for &'a mut Array<L, T> {
+    type IntoIter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<L: Arrayify<T>, T> ExactSizeIterator for ArrayIter<L, T> {}
+
+impl<L: Arrayify<T>, T> iter::FusedIterator for ArrayIter<L, T> {}
+
+
+impl<L: Arrayify<T>, T: fmt::Debug> fmt::Debug for Array<L, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        self.deref().fmt(fmt)
+    }
+}
+
+
+impl<L: Arrayify<T>, T: PartialEq> PartialEq for Array<L, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<L: Arrayify<T>, T: Eq> Eq for Array<L, T> {}
+
+/// `Array`s are compared lexicographically, exactly as slices are. Since both sides always have
+/// the same length, there's never any question of a shorter `Array` comparing as less.
+impl<L: Arrayify<T>, T: PartialOrd> PartialOrd for Array<L, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<L: Arrayify<T>, T: Ord> Ord for Array<L, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+
+/// Converting a `Vec` into an `Array` succeeds only if it has exactly `L` elements. Otherwise the
+/// `Vec` is handed back untouched, so nothing is lost.
+impl<L: Arrayify<T>, T> TryFrom<Vec<T>> for Array<L, T> {
+    type Error = Vec<T>;
+
+    fn try_from(v: Vec<T>) -> Result<Self, Vec<T>> {
+        if v.len() == L::reify() {
+            Ok(ArrayBuilder::collect(v))
+        } else {
+            Err(v)
+        }
+    }
+}
+
+
+impl<L: Arrayify<T>, T> From<Array<L, T>> for Vec<T> {
+    fn from(array: Array<L, T>) -> Vec<T> {
+        let mut vec = Vec::with_capacity(L::reify());
+        vec.extend(array);
+        vec
+    }
+}
+
+/// Converting a slice into an `Array` clones out its elements, provided it has exactly `L` of
+/// them. With the "specialization" feature, `Copy` elements are copied over in one go instead.
+impl<'a, L: Arrayify<T>, T: Clone> TryFrom<&'a [T]> for Array<L, T> {
+    type Error = LengthMismatch;
+
+    fn try_from(s: &'a [T]) -> Result<Self, LengthMismatch> {
+        if s.len() == L::reify() {
+            Ok(<Self as FromSliceCloned<T>>::from_slice_cloned(s))
+        } else {
+            Err(LengthMismatch {
+                expected: L::reify(),
+                actual: s.len(),
+            })
+        }
+    }
+}
+
+/// The element-wise half of `TryFrom<&[T]>`, split out so that it can be specialized for `Copy`
+/// elements. The slice must have exactly `L` elements.
+trait FromSliceCloned<T> {
+    fn from_slice_cloned(s: &[T]) -> Self;
+}
+
+impl<L: Arrayify<T>, T: Clone> FromSliceCloned<T> for Array<L, T> {
+    #[cfg(not(feature = "specialization"))]
+    fn from_slice_cloned(s: &[T]) -> Self {
+        ArrayBuilder::collect(s.iter().cloned())
+    }
+
+    #[cfg(feature = "specialization")]
+    default fn from_slice_cloned(s: &[T]) -> Self {
+        ArrayBuilder::collect(s.iter().cloned())
+    }
+}
+
+#[cfg(feature = "specialization")]
+impl<L: Arrayify<T>, T: Copy> FromSliceCloned<T> for Array<L, T> {
+    fn from_slice_cloned(s: &[T]) -> Self {
+        debug_assert_eq!(s.len(), L::reify());
+        unsafe {
+            let mut array = mem::MaybeUninit::<Array<L, T>>::uninit();
+            ptr::copy_nonoverlapping(s.as_ptr(), array.as_mut_ptr() as *mut T, L::reify());
+            array.assume_init()
+        }
+    }
+}
+
+
+impl<L: Arrayify<T>, T> SizedIterator<L> for Array<L, T> {}
+
+impl<L: Arrayify<T> + NatPred, T> NonEmpty<Zero<L>> for Array<Zero<L>, T>
+    where Array<Zero<L>, T>: ArraySplit<Zero<L>, T>,
+          Pred<L>: Arrayify<T>
+{
+    type Next = Array<Two<Pred<L>>, T>;
+
+    fn next(self) -> (T, Array<Two<Pred<L>>, T>) {
+        self.split_first()
+    }
+}
+
+impl<L: Arrayify<T> + NatTriple, T> NonEmpty<One<L>> for Array<One<L>, T>
+    where Array<One<L>, T>: ArraySplit<One<L>, T>,
+          Triple<L>: Arrayify<T>
+{
+    type Next = Array<Triple<L>, T>;
+
+    fn next(self) -> (T, Array<Triple<L>, T>) {
+        self.split_first()
+    }
+}
+
+impl<L: Arrayify<T>, T> NonEmpty<Two<L>> for Array<Two<L>, T>
+    where Array<Two<L>, T>: ArraySplit<Two<L>, T>
+{
+    type Next = Array<One<L>, T>;
+
+    fn next(self) -> (T, Array<One<L>, T>) {
+        self.split_first()
+    }
+}
+
+impl<L: Arrayify<T>, T> FromSizedIterator<L, T> for Array<L, T> {
+    fn from_sized_iter<I: SizedIterator<L, Item = T>>(iter: I) -> Self {
+        let mut array: Array<L, T>;
+        unsafe {
+            array = mem::uninitialized();
+            for (i, v) in iter.into_iter().enumerate() {
+                ptr::write(&mut array[i], v);
+            }
+        }
+        array
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tll::ternary::*;
+
+    use std::pin::Pin;
+
+    fn assert_unpin<U: Unpin>() {}
+
+    #[test]
+    fn array_is_unpin() {
+        assert_unpin::<Array<U0, i32>>();
+        assert_unpin::<Array<U5, u8>>();
+        assert_unpin::<Array<U8, i32>>();
+        assert_unpin::<Array<U9, String>>();
+
+        let mut array = array![42i32, 84, 126];
+        {
+            let mut pinned = Pin::new(&mut array);
+            pinned[1] = 168;
+        }
+        assert_eq!(Pin::into_inner(Pin::new(&mut array))[1], 168);
+    }
+
+    #[test]
+    fn split_first_1() {
+        let array = array![42i32];
+        assert_eq!(array.len(), 1);
+        let (head, array) = array.split_first();
+        assert_eq!(array.len(), 0);
+        assert_eq!(head, 42);
+    }
+
+    #[test]
+    fn split_first_2() {
+        let array = array![42i32, 84];
+        assert_eq!(array.len(), 2);
+        let (head, array) = array.split_first();
+        assert_eq!(array.len(), 1);
+        assert_eq!(head, 42);
+    }
+
+    #[test]
+    fn split_first_3() {
+        let array = array![42i32, 84, 126];
+        assert_eq!(array.len(), 3);
+        let (head, array) = array.split_first();
+        assert_eq!(array.len(), 2);
+        assert_eq!(head, 42);
+    }
+
+    #[test]
+    fn split_first_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(array.len(), 8);
+        let (head, array) = array.split_first();
+        assert_eq!(array.len(), 7);
+        assert_eq!(head, 42);
+    }
+
+    #[test]
+    fn split_first_9() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378];
+        assert_eq!(array.len(), 9);
+        let (head, array) = array.split_first();
+        assert_eq!(array.len(), 8);
+        assert_eq!(head, 42);
+    }
+
+    #[test]
     fn split_first_10() {
         let array = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378, 420];
         assert_eq!(array.len(), 10);
@@ -362,56 +2537,1779 @@ mod tests {
     }
 
     #[test]
-    fn split_last_1() {
-        let array = array![42i32];
-        assert_eq!(array.len(), 1);
-        let (tail, array) = array.split_last();
+    fn split_last_1() {
+        let array = array![42i32];
+        assert_eq!(array.len(), 1);
+        let (tail, array) = array.split_last();
+        assert_eq!(array.len(), 0);
+        assert_eq!(tail, 42);
+    }
+
+    #[test]
+    fn split_last_2() {
+        let array = array![42i32, 84];
+        assert_eq!(array.len(), 2);
+        let (tail, array) = array.split_last();
+        assert_eq!(array.len(), 1);
+        assert_eq!(tail, 84);
+    }
+
+    #[test]
+    fn split_last_3() {
+        let array = array![42i32, 84, 126];
+        assert_eq!(array.len(), 3);
+        let (tail, array) = array.split_last();
+        assert_eq!(array.len(), 2);
+        assert_eq!(tail, 126);
+    }
+
+    #[test]
+    fn split_last_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(array.len(), 8);
+        let (tail, array) = array.split_last();
+        assert_eq!(array.len(), 7);
+        assert_eq!(tail, 336);
+    }
+
+    #[test]
+    fn split_last_9() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378];
+        assert_eq!(array.len(), 9);
+        let (tail, array) = array.split_last();
+        assert_eq!(array.len(), 8);
+        assert_eq!(tail, 378);
+    }
+
+    #[test]
+    fn split_last_10() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378, 420];
+        assert_eq!(array.len(), 10);
+        let (tail, array) = array.split_last();
+        assert_eq!(array.len(), 9);
+        assert_eq!(tail, 420);
+    }
+
+    #[test]
+    fn to_array_of_refs_3() {
+        let array = array![42i32, 84, 126];
+        let refs = array.to_array_of_refs();
+        assert_eq!(refs.len(), 3);
+        for (r, x) in refs.iter().zip(array.iter()) {
+            assert_eq!(*r as *const i32, x as *const i32);
+        }
+    }
+
+    #[test]
+    fn to_array_of_mut_refs_3() {
+        let mut array = array![42i32, 84, 126];
+        {
+            let mut refs = array.to_array_of_mut_refs();
+            assert_eq!(refs.len(), 3);
+            *refs[1] = 0;
+        }
+        assert_eq!(*array, [42, 0, 126]);
+    }
+
+    #[test]
+    fn into_iter_partial_drop() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let array = array![rc.clone(), rc.clone(), rc.clone()];
+        assert_eq!(Rc::strong_count(&rc), 4);
+        let mut iter = array.into_iter();
+        let first = iter.next().unwrap();
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 2);
+        drop(first);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn into_iter_exhausted_drop() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let array = array![rc.clone(), rc.clone(), rc.clone()];
+        let taken = array.into_iter().collect::<Vec<_>>();
+        assert_eq!(Rc::strong_count(&rc), 4);
+        drop(taken);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn into_iter_rev() {
+        let array = array![42i32, 84, 126, 168];
+        assert_eq!(array.into_iter().rev().collect::<Vec<_>>(), [168, 126, 84, 42]);
+
+        let mut iter = array.into_iter();
+        assert_eq!(iter.next(), Some(42));
+        assert_eq!(iter.next_back(), Some(168));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(126));
+        assert_eq!(iter.next(), Some(84));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn into_iter_partial_drop_both_ends() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let array = array![rc.clone(), rc.clone(), rc.clone(), rc.clone()];
+        assert_eq!(Rc::strong_count(&rc), 5);
+        let mut iter = array.into_iter();
+        let first = iter.next().unwrap();
+        let last = iter.next_back().unwrap();
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop((first, last));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    fn assert_fused<I: iter::FusedIterator>(_: &I) {}
+
+    #[test]
+    fn into_iter_fused() {
+        let mut iter = array![42i32, 84].into_iter();
+        assert_fused(&iter);
+        assert_eq!(iter.by_ref().count(), 2);
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+    }
+
+    #[test]
+    fn into_iter_clone() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut iter = array![rc.clone(), rc.clone(), rc.clone(), rc.clone()].into_iter();
+        drop(iter.next());
+        drop(iter.next_back());
+        assert_eq!(Rc::strong_count(&rc), 3);
+
+        let cloned = iter.clone();
+        assert_eq!(Rc::strong_count(&rc), 5);
+        assert_eq!(cloned.len(), 2);
+        drop(cloned);
+        assert_eq!(Rc::strong_count(&rc), 3);
+
+        let strings = array![String::from("a"), String::from("b"), String::from("c")];
+        let mut iter = strings.into_iter();
+        iter.next();
+        let rest = iter.clone().collect::<Vec<_>>();
+        assert_eq!(rest, iter.collect::<Vec<_>>());
+        assert_eq!(rest, ["b", "c"]);
+    }
+
+    #[test]
+    fn into_iter_clone_panic_drops() {
+        use std::cell::Cell;
+        use std::panic::{self, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        // Counts its live clones through the shared `Rc`, and panics on the third clone.
+        struct Fragile(Rc<()>, Rc<Cell<usize>>);
+
+        impl Clone for Fragile {
+            fn clone(&self) -> Self {
+                self.1.set(self.1.get() + 1);
+                if self.1.get() == 3 {
+                    panic!("oh no");
+                }
+                Fragile(self.0.clone(), self.1.clone())
+            }
+        }
+
+        let rc = Rc::new(());
+        let clones = Rc::new(Cell::new(0));
+        let mut iter = Array::<U5, Fragile>::from_fn(|_| Fragile(rc.clone(), clones.clone()))
+            .into_iter();
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| iter.clone()));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 5);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn into_iter_debug() {
+        let mut iter = array![1i32, 2, 3, 4, 5].into_iter();
+        iter.next();
+        iter.next();
+        assert_eq!(format!("{:?}", iter),
+                   "ArrayIter { remaining: [3, 4, 5], pos: 2, pos_back: 5 }");
+        iter.next_back();
+        assert_eq!(format!("{:?}", iter), "ArrayIter { remaining: [3, 4], pos: 2, pos_back: 4 }");
+    }
+
+    #[test]
+    fn borrowing_into_iter() {
+        let mut array = array![42i32, 84, 126];
+        for x in &mut array {
+            *x += 1;
+        }
+        let mut sum = 0;
+        for x in &array {
+            sum += *x;
+        }
+        assert_eq!(sum, 43 + 85 + 127);
+        assert_eq!(array, array![43, 85, 127]);
+    }
+
+    #[test]
+    fn map_3() {
+        let array = array![42i32, 84, 126].map(|x| x.to_string());
+        assert_eq!(*array, ["42", "84", "126"]);
+    }
+
+    #[test]
+    fn zip_3() {
+        let array = array![42i32, 84, 126].zip(array!["a", "b", "c"]);
+        assert_eq!(*array, [(42, "a"), (84, "b"), (126, "c")]);
+    }
+
+    #[test]
+    fn zip_arrays_2() {
+        let array = zip_arrays![array![42i32, 84], array!['a', 'b']];
+        assert_eq!(*array, [(42, 'a'), (84, 'b')]);
+    }
+
+    #[test]
+    fn zip_arrays_3() {
+        let array = zip_arrays![array![42i32, 84], array!['a', 'b'], array![true, false]];
+        assert_eq!(*array, [(42, 'a', true), (84, 'b', false)]);
+    }
+
+    #[test]
+    fn zip_arrays_8() {
+        let array = zip_arrays![array![1i32], array![2u8], array![3i64], array!['4'],
+                                array!["5"], array![6u16], array![7.0f64], array![false]];
+        assert_eq!(*array, [(1, 2, 3, '4', "5", 6, 7.0, false)]);
+    }
+
+    #[test]
+    fn map_windows_1() {
+        let array = array![42i32, 84, 126];
+        let windows = array.map_windows::<U1, _, _>(|w| w[0]);
+        assert_eq!(*windows, [42, 84, 126]);
+    }
+
+    #[test]
+    fn map_windows_2() {
+        let array = array![42i32, 84, 126, 168];
+        let windows = array.map_windows::<U2, _, _>(|w: &Array<U2, i32>| w[0] + w[1]);
+        assert_eq!(*windows, [126, 210, 294]);
+    }
+
+    #[test]
+    fn map_windows_full() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let windows = array.map_windows::<U3, _, _>(|w| w.concat());
+        assert_eq!(*windows, ["abc"]);
+        assert_eq!(array[0], "a");
+    }
+
+    #[test]
+    fn argmin_max_0() {
+        let array: Array<U0, i32> = array![];
+        assert_eq!(array.argmin_max(), (None, None));
+    }
+
+    #[test]
+    fn argmin_max_1() {
+        let array = array![42i32];
+        assert_eq!(array.argmin_max(), (Some(0), Some(0)));
+    }
+
+    #[test]
+    fn argmin_max_2() {
+        let array = array![84i32, 42];
+        assert_eq!(array.argmin_max(), (Some(1), Some(0)));
+        let array = array![42i32, 42];
+        assert_eq!(array.argmin_max(), (Some(0), Some(0)));
+    }
+
+    #[test]
+    fn argmin_max_8() {
+        let array = array![168i32, 84, 336, 42, 210, 42, 336, 126];
+        assert_eq!(array.argmin_max(), (Some(3), Some(2)));
+    }
+
+    #[test]
+    fn count_if_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(array.count_if(|&x| x > 200), 4);
+        assert_eq!(array.count_if(|_| true), 8);
+        assert_eq!(array.count_if(|_| false), 0);
+    }
+
+    #[test]
+    fn count_if_typed_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        let all: BoundedNat<U9> = array.count_if_typed(|_| true);
+        assert_eq!(all.get(), 8);
+        assert_eq!(usize::from(array.count_if_typed(|&x| x % 84 == 0)), 4);
+    }
+
+    #[test]
+    fn apply_permutation_identity() {
+        use permutation::Identity;
+
+        let array = array![42i32, 84, 126].apply_permutation::<Identity>();
+        assert_eq!(*array, [42, 84, 126]);
+    }
+
+    #[test]
+    fn apply_permutation_reversal() {
+        use permutation::Reversal;
+
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        assert_eq!(*array.apply_permutation::<Reversal>(), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn apply_permutation_panic_drops() {
+        use permutation::Permutation;
+        use std::panic::{self, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        struct Broken;
+
+        unsafe impl Permutation<U3> for Broken {
+            fn get(i: usize) -> usize {
+                if i == 1 {
+                    panic!("oh no");
+                }
+                i
+            }
+        }
+
+        let rc = Rc::new(());
+        let array = array![rc.clone(), rc.clone(), rc.clone()];
+        let result = panic::catch_unwind(AssertUnwindSafe(|| array.apply_permutation::<Broken>()));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn apply_permutation_rotate() {
+        use permutation::RotateLeft;
+
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(*array.apply_permutation::<RotateLeft<U3>>(),
+                   [168, 210, 252, 294, 336, 42, 84, 126]);
+    }
+
+    #[test]
+    fn apply_permutation_perfect_shuffle() {
+        struct PerfectShuffle;
+
+        unsafe impl Permutation<U4> for PerfectShuffle {
+            fn get(i: usize) -> usize {
+                [0, 2, 1, 3][i]
+            }
+        }
+
+        let array = array![42i32, 84, 126, 168].apply_permutation::<PerfectShuffle>();
+        assert_eq!(*array, [42, 126, 84, 168]);
+    }
+
+    #[test]
+    fn rotate_by_one_0() {
+        let array: Array<U0, i32> = array![];
+        assert_eq!(array.rotate_by_one().len(), 0);
+    }
+
+    #[test]
+    fn rotate_by_one_1() {
+        assert_eq!(*array![42i32].rotate_by_one(), [42]);
+    }
+
+    #[test]
+    fn rotate_by_one_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(*array.rotate_by_one(), [84, 126, 168, 210, 252, 294, 336, 42]);
+    }
+
+    #[test]
+    fn into_boxed_slice_0() {
+        let array: Array<U0, String> = array![];
+        assert_eq!(array.into_boxed_slice().len(), 0);
+    }
+
+    #[test]
+    fn into_boxed_slice_3() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let boxed = array.into_boxed_slice();
+        assert_eq!(boxed.len(), 3);
+        assert_eq!(&*boxed, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn into_boxed_slice_drops() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let boxed = Array::<U9, Rc<()>>::fill(rc.clone()).into_boxed_slice();
+        assert_eq!(Rc::strong_count(&rc), 10);
+        drop(boxed);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn into_vec_3() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        assert_eq!(array.to_vec(), ["a", "b", "c"]);
+        let v = array.into_vec();
+        assert_eq!(v, ["a", "b", "c"]);
+        assert_eq!(v.capacity(), 3);
+    }
+
+    #[test]
+    fn into_array_box_3() {
+        let boxed = array![42i32, 84, 126].into_array_box();
+        assert_eq!(**boxed, [42, 84, 126]);
+    }
+
+    #[test]
+    fn try_from_boxed_slice_8() {
+        let boxed: Box<[String]> = (0..8).map(|i| i.to_string()).collect::<Vec<_>>().into();
+        let array = Array::<U8, String>::try_from_boxed_slice(boxed).unwrap();
+        assert_eq!(array[7], "7");
+        let boxed = array.into_boxed_slice();
+        assert_eq!(boxed.len(), 8);
+    }
+
+    #[test]
+    fn try_from_boxed_slice_wrong_length() {
+        let boxed = vec![42i32, 84, 126].into_boxed_slice();
+        let boxed = Array::<U2, i32>::try_from_boxed_slice(boxed).unwrap_err();
+        let boxed = Array::<U4, i32>::try_from_boxed_slice(boxed).unwrap_err();
+        assert_eq!(&*boxed, [42, 84, 126]);
+    }
+
+    #[test]
+    fn truncate_or_pad_shorter() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let array: Array<U1, String> = array.truncate_or_pad(String::new());
+        assert_eq!(*array, ["a"]);
+    }
+
+    #[test]
+    fn truncate_or_pad_same() {
+        let array: Array<U3, i32> = array![42i32, 84, 126].truncate_or_pad(0);
+        assert_eq!(*array, [42, 84, 126]);
+    }
+
+    #[test]
+    fn truncate_or_pad_longer() {
+        let array: Array<U8, i32> = array![42i32, 84, 126].truncate_or_pad(-1);
+        assert_eq!(*array, [42, 84, 126, -1, -1, -1, -1, -1]);
+    }
+
+    #[test]
+    fn map_result_collect_ok() {
+        let array = array!["42", "84", "126"].map_result_collect(|s| s.parse::<i32>()).unwrap();
+        assert_eq!(*array, [42, 84, 126]);
+    }
+
+    #[test]
+    fn map_result_collect_err() {
+        let array = array![String::from("42"),
+                           String::from("84"),
+                           String::from("x"),
+                           String::from("168"),
+                           String::from("210")];
+        let (_, rest) = array.map_result_collect(|s| s.parse::<i32>()).unwrap_err();
+        assert_eq!(rest, ["168", "210"]);
+    }
+
+    #[test]
+    fn map_result_collect_err_last() {
+        let array = array![42i32, 84, 126];
+        let (e, rest) = array.map_result_collect(|x| if x > 100 { Err(x) } else { Ok(x) })
+            .unwrap_err();
+        assert_eq!(e, 126);
+        assert!(rest.is_empty());
+    }
+
+    /// A tiny deterministic generator for "random" test inputs.
+    fn lcg_array<L: Arrayify<i32>>(seed: u32) -> Array<L, i32> {
+        let mut state = seed;
+        ArrayBuilder::collect(iter::repeat(()).map(|()| {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            (state >> 24) as i32 % 16
+        }))
+    }
+
+    #[test]
+    fn sliding_max_brute_force() {
+        for seed in 0..32 {
+            let array = lcg_array::<U16>(seed);
+            assert_eq!(*array.sliding_max::<U1>(), *array);
+            assert_eq!(*array.sliding_max::<U2>(),
+                       *array.map_windows::<U2, _, _>(|w| *w.iter().max().unwrap()));
+            assert_eq!(*array.sliding_max::<U3>(),
+                       *array.map_windows::<U3, _, _>(|w| *w.iter().max().unwrap()));
+            assert_eq!(*array.sliding_max::<U7>(),
+                       *array.map_windows::<U7, _, _>(|w| *w.iter().max().unwrap()));
+            assert_eq!(*array.sliding_max::<U16>(), [*array.iter().max().unwrap()]);
+        }
+    }
+
+    #[test]
+    fn sliding_min_brute_force() {
+        for seed in 0..32 {
+            let array = lcg_array::<U16>(seed);
+            assert_eq!(*array.sliding_min::<U1>(), *array);
+            assert_eq!(*array.sliding_min::<U2>(),
+                       *array.map_windows::<U2, _, _>(|w| *w.iter().min().unwrap()));
+            assert_eq!(*array.sliding_min::<U3>(),
+                       *array.map_windows::<U3, _, _>(|w| *w.iter().min().unwrap()));
+            assert_eq!(*array.sliding_min::<U7>(),
+                       *array.map_windows::<U7, _, _>(|w| *w.iter().min().unwrap()));
+            assert_eq!(*array.sliding_min::<U16>(), [*array.iter().min().unwrap()]);
+        }
+    }
+
+    #[test]
+    fn take_while_max_stops_at_predicate() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        let (taken, rest, tail) = array.take_while_max::<U5, _>(|&x| x < 100);
+        assert_eq!(taken, [42, 84]);
+        assert_eq!(rest, [126, 168, 210]);
+        assert_eq!(*tail, [252, 294, 336]);
+    }
+
+    #[test]
+    fn take_while_max_stops_at_k() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let (taken, rest, tail) = array.take_while_max::<U2, _>(|_| true);
+        assert_eq!(taken, ["a", "b"]);
+        assert!(rest.is_empty());
+        assert_eq!(*tail, ["c"]);
+    }
+
+    #[test]
+    fn take_while_max_predicate_resumes() {
+        let array = array![1i32, 2, 1, 2];
+        let (taken, rest, tail) = array.take_while_max::<U4, _>(|&x| x == 1);
+        assert_eq!(taken, [1]);
+        assert_eq!(rest, [2, 1, 2]);
+        assert_eq!(tail.len(), 0);
+    }
+
+    #[test]
+    fn stride_collect_2() {
+        let source = array![42i32, 84, 126, 168, 210, 252];
+        let array = Array::<U3, i32>::stride_collect::<_, 2>(source).unwrap();
+        assert_eq!(*array, [42, 126, 210]);
+    }
+
+    #[test]
+    fn stride_collect_3() {
+        let source = array![42i32, 84, 126, 168, 210, 252];
+        let array = Array::<U2, i32>::stride_collect::<_, 3>(source).unwrap();
+        assert_eq!(*array, [42, 168]);
+    }
+
+    #[test]
+    fn stride_collect_mismatch() {
+        let source = array![42i32, 84, 126, 168, 210, 252];
+        assert!(Array::<U2, i32>::stride_collect::<_, 2>(source).is_none());
+        assert!(Array::<U6, i32>::stride_collect::<_, 0>(source).is_none());
+    }
+
+    #[test]
+    fn stride_collect_exact_2() {
+        let source = array![String::from("a"), String::from("b"),
+                            String::from("c"), String::from("d")];
+        let array = Array::<U2, String>::stride_collect_exact::<U2>(source);
+        assert_eq!(*array, ["a", "c"]);
+    }
+
+    #[test]
+    fn stride_collect_exact_3() {
+        let source = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378];
+        let array = Array::<U3, i32>::stride_collect_exact::<U3>(source);
+        assert_eq!(*array, [42, 168, 294]);
+    }
+
+    #[test]
+    fn into_sorted_with_indices_8() {
+        let array = lcg_array::<U8>(7);
+        let sorted = array.into_sorted_with_indices();
+
+        let mut seen = [false; 8];
+        for &(i, x) in sorted.iter() {
+            assert!(!seen[i]);
+            seen[i] = true;
+            assert_eq!(x, array[i]);
+        }
+        for pair in sorted.windows(2) {
+            assert!(pair[0].1 <= pair[1].1);
+        }
+    }
+
+    #[test]
+    fn into_sorted_with_indices_stable() {
+        let array = array![2i32, 1, 2, 1, 0];
+        let indices = array.into_sorted_with_indices().map(|(i, _)| i);
+        assert_eq!(*indices, [4, 1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn gather_8() {
+        let source = lcg_array::<U8>(3);
+        let indices = array![7usize, 6, 5, 4, 3, 2, 1, 0, 0];
+        let gathered = Array::gather(&source, indices);
+        assert_eq!(gathered[0], source[7]);
+        assert_eq!(gathered[7], source[0]);
+        assert_eq!(gathered[8], source[0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_out_of_bounds() {
+        let source = array![42i32, 84, 126];
+        Array::gather(&source, array![0, 3]);
+    }
+
+    #[test]
+    fn gather_checked_out_of_bounds() {
+        let source = array![42i32, 84, 126];
+        assert!(Array::gather_checked(&source, array![0, 3]).is_none());
+        assert_eq!(*Array::gather_checked(&source, array![2, 1]).unwrap(), [126, 84]);
+    }
+
+    #[test]
+    fn scatter_gather_inverse() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let indices = array![2usize, 0, 1];
+        let mut dest = array![String::new(), String::new(), String::new()];
+        array.clone().scatter(indices, &mut dest);
+        assert_eq!(*dest, ["b", "c", "a"]);
+        assert_eq!(*Array::gather(&dest, indices), *array);
+    }
+
+    #[test]
+    fn into_chunks_remainder_10_3() {
+        let array = array![0i32, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let (chunks, rest) = array.into_chunks_remainder::<U3>();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(*chunks[0], [0, 1, 2]);
+        assert_eq!(*chunks[1], [3, 4, 5]);
+        assert_eq!(*chunks[2], [6, 7, 8]);
+        assert_eq!(*rest, [9]);
+    }
+
+    #[test]
+    fn into_chunks_remainder_exact() {
+        let array = array![String::from("a"), String::from("b"),
+                           String::from("c"), String::from("d")];
+        let (chunks, rest) = array.into_chunks_remainder::<U2>();
+        assert_eq!(*chunks[1], ["c", "d"]);
+        assert_eq!(rest.len(), 0);
+    }
+
+    #[test]
+    fn into_chunks_remainder_short() {
+        let (chunks, rest) = array![42i32, 84].into_chunks_remainder::<U3>();
+        assert_eq!(chunks.len(), 0);
+        assert_eq!(*rest, [42, 84]);
+    }
+
+    #[test]
+    fn map_accumulate_sqrt() {
+        let array = array![1.0f64, 4.0, 9.0, 16.0];
+        let (total, roots) = array.map_accumulate(0.0, |acc, x| (acc + x, x.sqrt()));
+        assert_eq!(total, 30.0);
+        assert_eq!(*roots, [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn map_accumulate_0() {
+        let empty: Array<U0, i32> = array![];
+        let (acc, array) = empty.map_accumulate(String::from("x"), |acc, x| (acc, x));
+        assert_eq!(acc, "x");
+        assert_eq!(array.len(), 0);
+    }
+
+    #[test]
+    fn map_accumulate_offsets() {
+        let words = array!["foo", "quux", "ba"];
+        let (end, offsets) = words.map_accumulate(0, |offset, w| (offset + w.len(), offset));
+        assert_eq!(end, 9);
+        assert_eq!(*offsets, [0, 3, 7]);
+    }
+
+    #[test]
+    fn zip3_8() {
+        let a = lcg_array::<U8>(1);
+        let b = lcg_array::<U8>(2).map(|x| x as u8);
+        let c = lcg_array::<U8>(3).map(|x| x.to_string());
+        let chained = a.zip(b).zip(c.clone()).map(|((t, u), v)| (t, u, v));
+        assert_eq!(*a.zip3(b, c), *chained);
+    }
+
+    #[test]
+    fn half_split_0() {
+        let array: Array<U0, String> = array![];
+        let (front, back) = array.half_split();
+        assert_eq!(front.len() + back.len(), 0);
+    }
+
+    #[test]
+    fn half_split_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        let (front, back) = array.half_split();
+        assert_eq!(*front, array[..4]);
+        assert_eq!(*back, array[4..]);
+    }
+
+    #[test]
+    fn into_pair_strings() {
+        let array = array![String::from("a"), String::from("b"),
+                           String::from("c"), String::from("d"),
+                           String::from("e"), String::from("f")];
+        let (first, second) = array.into_pair();
+        assert_eq!(*first, ["a", "b", "c"]);
+        assert_eq!(*second, ["d", "e", "f"]);
+    }
+
+    #[test]
+    fn from_vec_truncating_exact() {
+        let array = Array::<U3, String>::from_vec_truncating(vec![String::from("a"),
+                                                                  String::from("b"),
+                                                                  String::from("c")]);
+        assert_eq!(*array, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn from_vec_truncating_longer() {
+        let array = Array::<U3, i32>::from_vec_truncating((0..10).collect());
+        assert_eq!(*array, [0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_vec_truncating_shorter() {
+        Array::<U3, i32>::from_vec_truncating(vec![1, 2]);
+    }
+
+    #[test]
+    fn from_vec_padding_8() {
+        assert_eq!(*Array::<U8, i32>::from_vec_padding(vec![1, 2, 3], -1),
+                   [1, 2, 3, -1, -1, -1, -1, -1]);
+        assert_eq!(*Array::<U8, i32>::from_vec_padding((0..8).collect(), -1),
+                   [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(*Array::<U8, i32>::from_vec_padding((0..9).collect(), -1),
+                   [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn with_value_at_1() {
+        let array = array![String::from("a")];
+        assert_eq!(*array.clone().with_value_at(0, String::from("b")), ["b"]);
+        assert_eq!(*array.with_value_at_nat::<U0>(String::from("c")), ["c"]);
+    }
+
+    #[test]
+    fn with_value_at_2() {
+        let array = array![42i32, 84];
+        assert_eq!(*array.with_value_at(1, 0), [42, 0]);
+        assert_eq!(*array.with_value_at_nat::<U0>(0), [0, 84]);
+    }
+
+    #[test]
+    fn with_value_at_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(*array.with_value_at(5, 0), [42, 84, 126, 168, 210, 0, 294, 336]);
+        assert_eq!(*array.with_value_at_nat::<U7>(0), [42, 84, 126, 168, 210, 252, 294, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_value_at_out_of_bounds() {
+        array![42i32, 84].with_value_at(2, 0);
+    }
+
+    #[test]
+    fn with_value_at_drops_old() {
+        use std::rc::Rc;
+
+        let old = Rc::new(());
+        let new = Rc::new(());
+        let array = array![old.clone(), old.clone()].with_value_at(1, new.clone());
+        assert_eq!(Rc::strong_count(&old), 2);
+        assert_eq!(Rc::strong_count(&new), 2);
+        drop(array);
+        assert_eq!(Rc::strong_count(&old), 1);
+    }
+
+    fn byte_offset<L: Arrayify<T>, T>(array: &Array<L, T>, i: usize) -> usize {
+        &array[i] as *const T as usize - array as *const Array<L, T> as usize
+    }
+
+    #[test]
+    fn element_offset_layout() {
+        let bytes = lcg_array::<U11>(1).map(|x| x as u8);
+        assert_eq!(Array::<U11, u8>::element_offset::<U0>(), byte_offset(&bytes, 0));
+        assert_eq!(Array::<U11, u8>::element_offset::<U10>(), byte_offset(&bytes, 10));
+
+        let padded = lcg_array::<U9>(2).map(|x| (x as u8, x as u32));
+        assert_eq!(Array::<U9, (u8, u32)>::element_offset::<U4>(), byte_offset(&padded, 4));
+        assert_eq!(Array::<U9, (u8, u32)>::element_offset::<U8>(), byte_offset(&padded, 8));
+
+        let strings = lcg_array::<U5>(3).map(|x| x.to_string());
+        assert_eq!(Array::<U5, String>::element_offset::<U3>(), byte_offset(&strings, 3));
+    }
+
+    #[test]
+    fn map_pair_4() {
+        let array = array![42i32, 84, 126, 168];
+        let mapped = array.map_pair(|x| format!("even {}", x), |x| format!("odd {}", x));
+        assert_eq!(*mapped, ["even 42", "odd 84", "even 126", "odd 168"]);
+    }
+
+    #[test]
+    fn map_pair_calls() {
+        let mut evens = Vec::new();
+        let mut odds = Vec::new();
+        array![0i32, 1, 2, 3, 4, 5].map_pair(|x| evens.push(x), |x| odds.push(x));
+        assert_eq!(evens, [0, 2, 4]);
+        assert_eq!(odds, [1, 3, 5]);
+    }
+
+    #[test]
+    fn all_distinct_0() {
+        let array: Array<U0, i32> = array![];
+        assert!(array.all_distinct());
+        assert!(array.all_distinct_ord());
+    }
+
+    #[test]
+    fn all_distinct_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert!(array.all_distinct());
+        assert!(array.all_distinct_ord());
+    }
+
+    #[test]
+    fn all_distinct_one_duplicate() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 42];
+        assert!(!array.all_distinct());
+        assert!(!array.all_distinct_ord());
+
+        let array = array!["a", "b", "c", "b"];
+        assert!(!array.all_distinct());
+        assert!(!array.all_distinct_ord());
+    }
+
+    #[test]
+    fn symmetric_distance_swap() {
+        let a = lcg_array::<U8>(4);
+        let b = lcg_array::<U8>(5);
+        let metric = |x: i32, y: i32| (x - y).abs();
+        assert_eq!(*a.symmetric_distance(b, metric), *b.symmetric_distance(a, metric));
+    }
+
+    #[test]
+    fn symmetric_apply_strings() {
+        let a = array![String::from("a"), String::from("b")];
+        let b = array![String::from("c"), String::from("d")];
+        let joined = Array::symmetric_apply(a, b, |x, y| x + &y);
+        assert_eq!(*joined, ["ac", "bd"]);
+    }
+
+    #[test]
+    fn init_from_slice_checked_3() {
+        let words = [42i32, 84, 126, 168, 210];
+        let (init, rest) = Array::<U3, i32>::init_from_slice_checked(&words).unwrap();
+        assert_eq!(**init, [42, 84, 126]);
+        assert_eq!(rest, [168, 210]);
+
+        let (init, rest) = Array::<U5, i32>::init_from_slice_checked(&words).unwrap();
+        assert_eq!(init.len(), 5);
+        assert!(rest.is_empty());
+
+        assert!(Array::<U6, i32>::init_from_slice_checked(&words).is_none());
+    }
+
+    #[test]
+    fn init_from_slice_mut_checked_3() {
+        let mut words = [42i32, 84, 126, 168, 210];
+        {
+            let (init, rest) = Array::<U3, i32>::init_from_slice_mut_checked(&mut words).unwrap();
+            init[2] = 0;
+            rest[0] = 1;
+        }
+        assert_eq!(words, [42, 84, 0, 1, 210]);
+        assert!(Array::<U6, i32>::init_from_slice_mut_checked(&mut words).is_none());
+    }
+
+    #[test]
+    fn to_column_major_2() {
+        let columns = array![1i32, 2, 3, 4, 5, 6].to_column_major::<U2>();
+        assert_eq!(columns.len(), 2);
+        assert_eq!(*columns[0], [1, 3, 5]);
+        assert_eq!(*columns[1], [2, 4, 6]);
+    }
+
+    #[test]
+    fn to_row_major_2() {
+        let rows = array![1i32, 2, 3, 4, 5, 6].to_row_major::<U2>();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(*rows[0], [1, 2]);
+        assert_eq!(*rows[1], [3, 4]);
+        assert_eq!(*rows[2], [5, 6]);
+    }
+
+    #[test]
+    fn to_column_major_strings() {
+        let array = lcg_array::<U12>(6).map(|x| x.to_string());
+        let columns = array.clone().to_column_major::<U4>();
+        let rows = array.clone().to_row_major::<U4>();
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(columns[j][i], array[i * 4 + j]);
+                assert_eq!(rows[i][j], array[i * 4 + j]);
+            }
+        }
+    }
+
+    #[test]
+    fn get_nat_8() {
+        let mut array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(*array.get_nat::<U0>(), 42);
+        assert_eq!(*array.get_nat::<U7>(), 336);
+        *array.get_nat_mut::<U3>() = 0;
+        assert_eq!(array[3], 0);
+        assert_eq!(unsafe { *array.get_unchecked_nat::<U5>() }, 252);
+    }
+
+    #[test]
+    fn cycle_map_8() {
+        let array = lcg_array::<U8>(8);
+        let differences = array.cycle_map(|i, a| a[(i + 1) % 8] - a[i]);
+        assert_eq!(differences.iter().sum::<i32>(), 0);
+        assert_eq!(differences[7], array[0] - array[7]);
+    }
+
+    #[test]
+    fn cycle_shift_map_1() {
+        assert_eq!(*array![42i32].cycle_shift_map(|a, b| a - b), [0]);
+    }
+
+    #[test]
+    fn cycle_shift_map_differences() {
+        let array = array![3i32, 1, 4, 1, 5, 9, 2, 6];
+        let differences = array.cycle_shift_map(|a, b| b - a);
+        assert_eq!(*differences, [-2, 3, -3, 4, 4, -7, 4, -3]);
+        assert_eq!(*differences, *array.cycle_map(|i, a| a[(i + 1) % 8] - a[i]));
+    }
+
+    #[test]
+    fn cycle_shift_map_strings() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let pairs = array.cycle_shift_map(|a, b| a + &b);
+        assert_eq!(*pairs, ["ab", "bc", "ca"]);
+    }
+
+    #[test]
+    fn fold_pair_1() {
+        assert_eq!(array![42i32].fold_pair(|_, _| unreachable!()), 42);
+    }
+
+    #[test]
+    fn fold_pair_max() {
+        let array = lcg_array::<U16>(9);
+        assert_eq!(array.fold_pair(i32::max), *array.iter().max().unwrap());
+    }
+
+    #[test]
+    fn fold_pair_tree() {
+        let array = array![1i32, 2, 3, 4, 5, 6, 7, 8];
+        let mut calls = Vec::new();
+        array.fold_pair(|a, b| {
+            calls.push((a, b));
+            a + b
+        });
+        assert_eq!(calls, [(1, 2), (3, 4), (5, 6), (7, 8), (3, 7), (11, 15), (10, 26)]);
+    }
+
+    #[test]
+    fn fold_pair_strings() {
+        let array: Array<U16, String> = ArrayBuilder::collect((0..16).map(|i| format!("{:x}", i)));
+        assert_eq!(array.fold_pair(|a, b| a + &b), "0123456789abcdef");
+    }
+
+    #[test]
+    fn fold_pair_panic_drops() {
+        use std::panic::{self, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        // Panic partway through the second level, when some results and some inputs are live.
+        let rc = Rc::new(());
+        let array: Array<U8, Vec<Rc<()>>> = ArrayBuilder::collect((0..8).map(|_| vec![rc.clone()]));
+        let mut calls = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            array.fold_pair(|mut a, b| {
+                calls += 1;
+                if calls == 6 {
+                    panic!("oh no");
+                }
+                a.extend(b);
+                a
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn index_bounded_nat() {
+        let mut array = array![42i32, 84, 126, 168];
+        let i = BoundedNat::<U4>::new(2).unwrap();
+        assert_eq!(array[i], 126);
+        array[i] = 0;
+        assert_eq!(*array, [42, 84, 0, 168]);
+        assert_eq!(array[3], 168);
+        assert_eq!(array[1..], [84, 0, 168]);
+    }
+
+    #[test]
+    fn map_ref_borrows() {
+        let array = array![String::from("a"), String::from("bb"), String::from("ccc")];
+        let firsts = array.map_ref(|s| &s[..1]);
+        assert_eq!(*firsts, ["a", "b", "c"]);
+        assert_eq!(*array.map_ref(|s| s.len()), [1, 2, 3]);
+    }
+
+    #[test]
+    fn map_in_place_8() {
+        let mut array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        let before = array.as_ptr();
+        array.map_in_place(|x| *x /= 42);
+        assert_eq!(*array, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(array.as_ptr(), before);
+    }
+
+    #[test]
+    fn map_in_place_strings() {
+        let mut array = array![String::from("a"), String::from("b")];
+        let buffers = array.map_ref(|s| s.as_ptr());
+        array.map_in_place(|s| s.make_ascii_uppercase());
+        assert_eq!(*array, ["A", "B"]);
+        assert_eq!(*array.map_ref(|s| s.as_ptr()), *buffers);
+    }
+
+    #[test]
+    fn to_slice_copy_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(array.to_slice_copy::<8>(), Some([42, 84, 126, 168, 210, 252, 294, 336]));
+        assert_eq!(array.to_slice_copy::<7>(), None);
+        assert_eq!(array.to_slice_copy::<9>(), None);
+    }
+
+    #[test]
+    fn to_slice_copy_0() {
+        let array: Array<U0, u8> = array![];
+        assert_eq!(array.to_slice_copy::<0>(), Some([]));
+    }
+
+    #[test]
+    fn zip_min_max_8() {
+        use std::cmp;
+
+        let a = lcg_array::<U8>(10);
+        let b = lcg_array::<U8>(11);
+        let (mins, maxes) = a.zip_min_max(b);
+        let expected = a.zip(b).map(|(x, y)| (cmp::min(x, y), cmp::max(x, y)));
+        assert_eq!(*mins.zip(maxes), *expected);
+    }
+
+    #[test]
+    fn zip_min_max_strings() {
+        let a = array![String::from("b"), String::from("a"), String::from("c")];
+        let b = array![String::from("a"), String::from("b"), String::from("c")];
+        let (mins, maxes) = a.zip_min_max(b);
+        assert_eq!(*mins, ["a", "a", "c"]);
+        assert_eq!(*maxes, ["b", "b", "c"]);
+    }
+
+    #[test]
+    fn checked_transmute_floats() {
+        let array = array![1.0f32, -0.0, 0.5];
+        let bits = unsafe { array.checked_transmute::<u32>() };
+        assert_eq!(*bits, *array.map(f32::to_bits));
+        let back = unsafe { bits.checked_transmute::<f32>() };
+        assert_eq!(*back, *array);
+    }
+
+    #[test]
+    fn copy_from_array_8() {
+        let src = lcg_array::<U8>(12);
+        let mut array = lcg_array::<U8>(13);
+        array.copy_from_array(&src);
+        assert_eq!(*array, *src);
+    }
+
+    #[test]
+    fn windows_reduce_sums() {
+        let array = lcg_array::<U12>(14);
+        let sums = array.windows_reduce::<U4, _>(|a, b| a + b);
+        assert_eq!(*sums, *array.map_windows::<U4, _, _>(|w| w.iter().sum()));
+    }
+
+    #[test]
+    fn windows_reduce_associative_min_max() {
+        use reduce::{Min, Max};
+
+        let array = lcg_array::<U27>(15);
+        assert_eq!(*array.windows_reduce_associative::<U1, Max>(), *array);
+        assert_eq!(*array.windows_reduce_associative::<U5, Max>(), *array.sliding_max::<U5>());
+        assert_eq!(*array.windows_reduce_associative::<U5, Min>(), *array.sliding_min::<U5>());
+        assert_eq!(*array.windows_reduce_associative::<U27, Min>(), *array.sliding_min::<U27>());
+    }
+
+    #[test]
+    fn windows_reduce_associative_order() {
+        struct Concat;
+
+        impl AssociativeOp<String> for Concat {
+            fn combine(a: String, b: String) -> String {
+                a + &b
+            }
+        }
+
+        let array = array!["a", "b", "c", "d", "e", "f", "g"].map(String::from);
+        let naive = array.windows_reduce::<U3, _>(Concat::combine);
+        assert_eq!(*array.windows_reduce_associative::<U3, Concat>(), *naive);
+        assert_eq!(naive[2], "cde");
+    }
+
+    #[test]
+    fn as_ref_array_3() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let refs = array.as_ref_array();
+        for i in 0..3 {
+            assert!(refs[i] as *const String == &array[i] as *const String);
+        }
+    }
+
+    #[test]
+    fn from_exact_iter_8() {
+        let array = Array::<U8, String>::from_exact_iter((0..8).map(|x| x.to_string()));
+        assert_eq!(*array, ["0", "1", "2", "3", "4", "5", "6", "7"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an iterator of length 8, but it has length 7")]
+    fn from_exact_iter_short() {
+        Array::<U8, i32>::from_exact_iter(0..7);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an iterator of length 8, but it has length 9")]
+    fn from_exact_iter_long() {
+        Array::<U8, i32>::from_exact_iter(vec![0; 9]);
+    }
+
+    #[test]
+    fn drain_range_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+
+        let (rest, drained) = array.drain_range::<U0, U3>();
+        assert_eq!(*rest, [168, 210, 252, 294, 336]);
+        assert_eq!(drained, [42, 84, 126]);
+
+        let (rest, drained) = array.drain_range::<U5, U8>();
+        assert_eq!(*rest, [42, 84, 126, 168, 210]);
+        assert_eq!(drained, [252, 294, 336]);
+
+        let (rest, drained) = array.drain_range::<U4, U4>();
+        assert_eq!(*rest, *array);
+        assert!(drained.is_empty());
+
+        let (rest, drained) = array.drain_range::<U0, U8>();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(drained, array.to_vec());
+    }
+
+    #[test]
+    fn drain_range_strings() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let (rest, drained) = array.drain_range::<U1, U2>();
+        assert_eq!(*rest, ["a", "c"]);
+        assert_eq!(drained, ["b"]);
+    }
+
+    #[test]
+    fn insert_at_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(*array.insert_at::<U0>(0), [0, 42, 84, 126, 168, 210, 252, 294, 336]);
+        assert_eq!(*array.insert_at::<U4>(0), [42, 84, 126, 168, 0, 210, 252, 294, 336]);
+        assert_eq!(*array.insert_at::<U8>(0), [42, 84, 126, 168, 210, 252, 294, 336, 0]);
+    }
+
+    #[test]
+    fn insert_at_drain_range_inverse() {
+        let array = array![String::from("a"), String::from("b"), String::from("c")];
+        let (rest, mut drained) = array.clone().drain_range::<U1, U2>();
+        assert_eq!(*rest.insert_at::<U1>(drained.pop().unwrap()), *array);
+    }
+
+    #[test]
+    fn map_types() {
+        let array = array![1u8, 2, 3].map(|x| x.to_string()).map(|s| s.len() as f64);
+        assert_eq!(*array, [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn map_panic_drops() {
+        use std::panic::{self, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let array = array![rc.clone(), rc.clone(), rc.clone(), rc.clone(), rc.clone()];
+        assert_eq!(Rc::strong_count(&rc), 6);
+
+        let mut calls = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            array.map(|x| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("oh no");
+                }
+                (x, Rc::new(()))
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn unzip_zip_inverse() {
+        let a = lcg_array::<U8>(16);
+        let b = lcg_array::<U8>(17).map(|x| x.to_string());
+        let (c, d) = a.zip(b.clone()).unzip();
+        assert_eq!(*c, *a);
+        assert_eq!(*d, *b);
+    }
+
+    #[test]
+    fn unzip_0() {
+        let array: Array<U0, (i32, String)> = array![];
+        let (a, b) = array.unzip();
+        assert_eq!(a.len() + b.len(), 0);
+    }
+
+    #[test]
+    fn fold_0() {
+        let array: Array<U0, i32> = array![];
+        assert_eq!(array.fold(42, |_, _| unreachable!()), 42);
+    }
+
+    #[test]
+    fn fold_order() {
+        let array = array![1i32, 2, 3, 4];
+        assert_eq!(array.fold(0, |acc, x| acc * 10 + x), 1234);
+    }
+
+    #[test]
+    fn fold_moves() {
+        let array = array![vec![1i32], vec![2, 3], vec![]];
+        let all = array.fold(Vec::new(), |mut acc, mut v| {
+            acc.append(&mut v);
+            acc
+        });
+        assert_eq!(all, [1, 2, 3]);
+    }
+
+    #[test]
+    fn scan_sums() {
+        let array = array![42i32, 84, 126, 168];
+        assert_eq!(*array.scan(0, |acc, x| acc + x), [42, 126, 252, 420]);
+        assert_eq!(*array.scan(0, |acc, x| acc + x), *array.cumsum());
+        assert_eq!(*array.scan_exclusive(0, |acc, x| acc + x), [0, 42, 126, 252]);
+    }
+
+    #[test]
+    fn scan_strings() {
+        let array = array!['a', 'b', 'c'];
+        assert_eq!(*array.scan(String::new(), |acc, c| acc + &c.to_string()), ["a", "ab", "abc"]);
+        assert_eq!(*array.scan_exclusive(String::new(), |acc, c| acc + &c.to_string()),
+                   ["", "a", "ab"]);
+    }
+
+    #[test]
+    fn reverse_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(*array.reverse(), [336, 294, 252, 210, 168, 126, 84, 42]);
+        assert_eq!(*array.reverse().reverse(), *array);
+        assert_eq!(*array.reverse(), *array.apply_permutation::<permutation::Reversal>());
+    }
+
+    #[test]
+    fn reverse_in_place_strings() {
+        let mut array = array![String::from("a"), String::from("b"), String::from("c")];
+        array.reverse_in_place();
+        assert_eq!(*array, ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(array![42i32, 84, 126], array![42, 84, 126]);
+        assert!(array![42i32, 84, 126] != array![42, 84, 0]);
+        assert_eq!(array![String::from("a")], array![String::from("a")]);
+    }
+
+    #[test]
+    fn ord_lexicographic() {
+        assert!(array![1i32, 2, 3] < array![1, 2, 4]);
+        assert!(array![1i32, 9, 9] < array![2, 0, 0]);
+        assert_eq!(array![1i32, 2, 3].cmp(&array![1, 2, 3]), Ordering::Equal);
+        assert_eq!(array![1.0f64, 2.0].partial_cmp(&array![1.0, ::std::f64::NAN]), None);
+    }
+
+    #[test]
+    fn ord_btree_map_key() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(array![2i32, 0], "c");
+        map.insert(array![1i32, 5], "b");
+        map.insert(array![1i32, 0], "a");
+        assert_eq!(map.values().cloned().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(map[&array![1, 5]], "b");
+    }
+
+    #[test]
+    fn from_fn_27() {
+        let array = Array::<U27, String>::from_fn(|i| i.to_string());
+        assert_eq!(array[0], "0");
+        assert_eq!(array[26], "26");
+    }
+
+    #[test]
+    fn from_fn_panic_drops() {
+        use std::panic::{self, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            Array::<U8, Rc<()>>::from_fn(|i| {
+                if i == 5 {
+                    panic!("oh no");
+                }
+                rc.clone()
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn fill_clones() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let array = Array::<U8, Rc<()>>::fill(rc.clone());
+        assert_eq!(Rc::strong_count(&rc), 9);
+        drop(array);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let array = Array::<U0, Rc<()>>::fill(rc.clone());
         assert_eq!(array.len(), 0);
-        assert_eq!(tail, 42);
+        assert_eq!(Rc::strong_count(&rc), 1);
     }
 
     #[test]
-    fn split_last_2() {
-        let array = array![42i32, 84];
-        assert_eq!(array.len(), 2);
-        let (tail, array) = array.split_last();
-        assert_eq!(array.len(), 1);
-        assert_eq!(tail, 84);
+    fn splat_9() {
+        assert_eq!(*Array::<U9, u8>::splat(42), [42; 9]);
     }
 
     #[test]
-    fn split_last_3() {
-        let array = array![42i32, 84, 126];
-        assert_eq!(array.len(), 3);
-        let (tail, array) = array.split_last();
-        assert_eq!(array.len(), 2);
-        assert_eq!(tail, 126);
+    fn fill_slice_method() {
+        let mut array = array![1i32, 2, 3];
+        array.fill(0);
+        assert_eq!(*array, [0, 0, 0]);
     }
 
     #[test]
-    fn split_last_8() {
+    fn try_map_ok() {
+        let array = array![1u32, 2, 3].try_map(|x| if x > 0 { Ok(x * 2) } else { Err(x) });
+        assert_eq!(*array.unwrap(), [2, 4, 6]);
+    }
+
+    #[test]
+    fn try_map_err_drops() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let array = array![(0, rc.clone()), (1, rc.clone()), (2, rc.clone()), (3, rc.clone())];
+        let result = array.try_map(|(i, rc)| if i == 2 { Err(i) } else { Ok(rc) });
+        assert_eq!(result.unwrap_err(), 2);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn zip_with_zip_map() {
+        let a = lcg_array::<U9>(18);
+        let b = lcg_array::<U9>(19).map(|x| x as i64);
+        let expected = a.zip(b).map(|(x, y)| x as i64 * y);
+        assert_eq!(a.zip_with(b, |x, y| x as i64 * y), expected);
+    }
+
+    #[test]
+    fn zip_with_strings() {
+        let a = array![String::from("a"), String::from("b")];
+        let b = array![1usize, 2];
+        assert_eq!(*a.zip_with(b, |s, n| s.repeat(n)), ["a", "bb"]);
+    }
+
+    #[test]
+    fn unzip_moves() {
+        use std::rc::Rc;
+
+        let a = Rc::new(());
+        let b = Rc::new(());
+        let array = Array::<U8, _>::fill((a.clone(), b.clone()));
+        let (firsts, seconds) = array.unzip();
+        assert_eq!(Rc::strong_count(&a), 9);
+        assert_eq!(Rc::strong_count(&b), 9);
+        assert!(firsts.iter().all(|x| Rc::ptr_eq(x, &a)));
+        drop(seconds);
+        assert_eq!(Rc::strong_count(&a), 9);
+        assert_eq!(Rc::strong_count(&b), 1);
+    }
+
+    #[test]
+    fn flatten_row_major_inverse() {
+        let array = lcg_array::<U12>(20).map(|x| x.to_string());
+        let rows = array.clone().to_row_major::<U3>();
+        assert_eq!(rows.flatten(), array);
+    }
+
+    #[test]
+    fn flatten_0() {
+        let nested: Array<U0, Array<U4, i32>> = array![];
+        assert_eq!(nested.flatten().len(), 0);
+        let nested: Array<U3, Array<U0, i32>> = array![array![], array![], array![]];
+        assert_eq!(nested.flatten().len(), 0);
+    }
+
+    #[test]
+    fn concat_0() {
+        let empty: Array<U0, i32> = array![];
+        assert_eq!(empty.concat(array![42, 84]), array![42, 84]);
+        assert_eq!(array![42i32, 84].concat(empty), array![42, 84]);
+    }
+
+    #[test]
+    fn concat_carry() {
+        let a = Array::<U8, i32>::from_fn(|i| i as i32);
+        let b = Array::<U19, i32>::from_fn(|i| i as i32 + 8);
+        let joined: Array<U27, i32> = a.concat(b);
+        assert_eq!(joined, Array::from_fn(|i| i as i32));
+    }
+
+    #[test]
+    fn concat_drops() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let joined = array![rc.clone()].concat(array![rc.clone(), rc.clone()]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        drop(joined);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn split_at_8() {
         let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
-        assert_eq!(array.len(), 8);
-        let (tail, array) = array.split_last();
-        assert_eq!(array.len(), 7);
-        assert_eq!(tail, 336);
+
+        let (front, back) = array.split_at::<U0>();
+        assert_eq!(front.len(), 0);
+        assert_eq!(back, array);
+
+        let (front, back) = array.split_at::<U3>();
+        assert_eq!(front, array![42, 84, 126]);
+        assert_eq!(back, array![168, 210, 252, 294, 336]);
+
+        let (front, back) = array.split_at::<U8>();
+        assert_eq!(front, array);
+        assert_eq!(back.len(), 0);
     }
 
     #[test]
-    fn split_last_9() {
-        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378];
+    fn split_at_concat_inverse() {
+        let array = Array::<U27, String>::from_fn(|i| i.to_string());
+        let (front, back) = array.clone().split_at::<U10>();
+        assert_eq!(front.concat(back), array);
+    }
+
+    #[test]
+    fn front_back() {
+        let mut array = array![42i32, 84, 126, 168];
+        assert_eq!(*array.front(), 42);
+        assert_eq!(*array.back(), 168);
+
+        *array.front_mut() = 0;
+        *array.back_mut() += 1;
+        assert_eq!(array, array![0, 84, 126, 169]);
+
+        let single = array![String::from("foo")];
+        assert!(ptr::eq(single.front(), single.back()));
+    }
+
+    #[test]
+    fn push_back_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336].push_back(378);
         assert_eq!(array.len(), 9);
-        let (tail, array) = array.split_last();
-        assert_eq!(array.len(), 8);
-        assert_eq!(tail, 378);
+        assert_eq!(array, array![42, 84, 126, 168, 210, 252, 294, 336, 378]);
+
+        let from_empty = Array::<U0, String>::from_fn(|_| unreachable!())
+            .push_back(String::from("foo"))
+            .push_back(String::from("bar"));
+        assert_eq!(*from_empty, [String::from("foo"), String::from("bar")]);
     }
 
     #[test]
-    fn split_last_10() {
-        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336, 378, 420];
-        assert_eq!(array.len(), 10);
-        let (tail, array) = array.split_last();
+    fn push_front_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336].push_front(0);
         assert_eq!(array.len(), 9);
-        assert_eq!(tail, 420);
+        assert_eq!(array, array![0, 42, 84, 126, 168, 210, 252, 294, 336]);
+    }
+
+    #[test]
+    fn push_front_back() {
+        let array = array![String::from("b")]
+            .push_front(String::from("a"))
+            .push_back(String::from("c"))
+            .push_front(String::from("_"));
+        assert_eq!(array.iter().map(|s| &s[..]).collect::<Vec<_>>(), ["_", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn rotate_left_typed_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(array.rotate_left_typed::<U0>(), array);
+        assert_eq!(array.rotate_left_typed::<U1>(), array.rotate_by_one());
+        assert_eq!(array.rotate_left_typed::<U3>(),
+                   array![168, 210, 252, 294, 336, 42, 84, 126]);
+        assert_eq!(array.rotate_left_typed::<U8>(), array);
+    }
+
+    #[test]
+    fn rotate_left_typed_permutation() {
+        use permutation::RotateLeft;
+
+        let array = Array::<U13, String>::from_fn(|i| i.to_string());
+        assert_eq!(array.clone().rotate_left_typed::<U5>(),
+                   array.apply_permutation::<RotateLeft<U5>>());
+    }
+
+    #[test]
+    fn rotate_right_typed_8() {
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+        assert_eq!(array.rotate_right_typed::<U0>(), array);
+        assert_eq!(array.rotate_right_typed::<U3>(),
+                   array![252, 294, 336, 42, 84, 126, 168, 210]);
+        assert_eq!(array.rotate_right_typed::<U8>(), array);
+        assert_eq!(array.rotate_left_typed::<U5>().rotate_right_typed::<U5>(), array);
+    }
+
+    #[test]
+    fn iter_3() {
+        let mut array = array![42i32, 84, 126];
+        assert_eq!(array.iter().len(), 3);
+        array.iter_mut().rev().for_each(|x| *x /= 42);
+        assert_eq!(array.iter().cloned().collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn array_repeat() {
+        let zeroes = array![0u32; U8];
+        assert_eq!(zeroes, Array::<U8, u32>::splat(0));
+
+        let strings = array![String::from("foo"); U3];
+        assert_eq!(*strings, ["foo", "foo", "foo"]);
+
+        let empty: Array<U0, i32> = array![42; U0];
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn array_init_8() {
+        let squares = array_init!(|i: usize| i * i, U8);
+        assert_eq!(*squares, [0, 1, 4, 9, 16, 25, 36, 49]);
+
+        fn label(i: usize) -> String {
+            format!("#{}", i)
+        }
+        assert_eq!(*array_init!(label, U3), ["#0", "#1", "#2"]);
+    }
+
+    #[test]
+    fn concat_arrays_4() {
+        let single = concat_arrays!(array![42i32]);
+        assert_eq!(*single, [42]);
+
+        let joined = concat_arrays!(array![1i32, 2], array![], array![3, 4, 5], array![6]; U6);
+        assert_eq!(*joined, [1, 2, 3, 4, 5, 6]);
+
+        let strings = concat_arrays!(array![String::from("a")], array![String::from("b")]);
+        assert_eq!(*strings, ["a", "b"]);
+    }
+
+    #[test]
+    fn as_ref_as_mut() {
+        use std::io::Read;
+
+        fn total<S: AsRef<[i32]>>(s: S) -> i32 {
+            s.as_ref().iter().sum()
+        }
+
+        let mut array = array![42i32, 84, 126];
+        assert_eq!(total(&array), 252);
+        array.as_mut()[0] = 0;
+        assert_eq!(total(array), 210);
+
+        let mut buf = Array::<U4, u8>::splat(0);
+        (&b"abcdef"[..]).read_exact(buf.as_mut()).unwrap();
+        assert_eq!(&buf.as_ref()[..], b"abcd");
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let array = Array::<U3, String>::try_from(vec![String::from("a"), String::from("b"),
+                                                        String::from("c")]).unwrap();
+        assert_eq!(*array, ["a", "b", "c"]);
+
+        let short = Array::<U4, i32>::try_from(vec![1, 2, 3]).unwrap_err();
+        assert_eq!(short, [1, 2, 3]);
+        let long = Array::<U2, i32>::try_from(short).unwrap_err();
+        assert_eq!(long, [1, 2, 3]);
+
+        let empty = Array::<U0, i32>::try_from(Vec::new()).unwrap();
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let v: Vec<i32> = (0..8).collect();
+        let array = Array::<U8, i32>::try_from(&v[..]).unwrap();
+        assert_eq!(*array, *v);
+
+        let err = Array::<U9, i32>::try_from(&v[..]).unwrap_err();
+        assert_eq!(err, LengthMismatch { expected: 9, actual: 8 });
+
+        let strings = [String::from("a"), String::from("b")];
+        let array = Array::<U2, String>::try_from(&strings[..]).unwrap();
+        assert_eq!(*array, strings);
+        assert!(Array::<U1, String>::try_from(&strings[..]).is_err());
+    }
+
+    #[test]
+    fn vec_from_array() {
+        let v = Vec::from(array![String::from("a"), String::from("b"), String::from("c")]);
+        assert_eq!(v, ["a", "b", "c"]);
+        assert_eq!(v.capacity(), 3);
+
+        let v: Vec<i32> = array![42i32, 84].into();
+        assert_eq!(Array::<U2, i32>::try_from(v).unwrap(), array![42, 84]);
+    }
+
+    #[test]
+    fn as_ptr_3() {
+        let mut array = array![42i32, 84, 126];
+        assert_eq!(array.as_ptr(), array[..].as_ptr());
+        assert_eq!(array.as_ptr() as usize, &array as *const _ as usize);
+        unsafe {
+            *array.as_mut_ptr().offset(2) = 0;
+        }
+        assert_eq!(*array, [42, 84, 0]);
+    }
+
+    #[test]
+    fn length_const() {
+        const LENGTH: usize = Array::<U27, String>::LENGTH;
+        assert_eq!(LENGTH, 27);
+        assert_eq!(Array::<U0, i32>::LENGTH, 0);
+        assert_eq!(Array::<U8, ()>::LENGTH, array![(); U8].len());
+    }
+
+    #[test]
+    fn sort_8() {
+        let array = lcg_array::<U8>(42);
+        let mut expected = array.to_vec();
+        expected.sort();
+
+        let mut sorted = array;
+        sorted.sort();
+        assert_eq!(*sorted, *expected);
+
+        let mut sorted = array;
+        sorted.sort_unstable();
+        assert_eq!(*sorted, *expected);
+
+        let mut sorted = array;
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        expected.reverse();
+        assert_eq!(*sorted, *expected);
+    }
+
+    #[test]
+    fn sort_by_stable() {
+        let mut array = array![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+        array.sort_by_key(|&(k, _)| k);
+        assert_eq!(array, array![(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+
+        array.sort_by(|a, b| b.0.cmp(&a.0));
+        assert_eq!(array, array![(1, 'a'), (1, 'c'), (0, 'b'), (0, 'd')]);
+    }
+
+    #[test]
+    fn binary_search_8() {
+        let array = array![0i32, 2, 4, 6, 8, 10, 12, 14];
+        assert_eq!(array.binary_search(&6), Ok(3));
+        assert_eq!(array.binary_search(&7), Err(4));
+        assert_eq!(array.binary_search_by_key(&3, |x| x / 2), Ok(3));
+
+        for x in -1..16 {
+            match (array.binary_search_typed(&x), array.binary_search(&x)) {
+                (Ok(i), Ok(j)) => {
+                    assert_eq!(i.get(), j);
+                    assert_eq!(array[i], x);
+                }
+                (Err(i), Err(j)) => assert_eq!(i.get(), j),
+                _ => panic!("binary_search_typed disagrees with binary_search at {}", x),
+            }
+        }
+        assert_eq!(array.binary_search_typed(&100).unwrap_err().get(), 8);
+    }
+
+    #[test]
+    fn is_sorted_8() {
+        let mut array = lcg_array::<U8>(7);
+        assert!(array![(); U0].is_sorted());
+        assert!(array![42i32].is_sorted());
+        array.sort();
+        assert!(array.is_sorted());
+        assert!(!array.is_sorted_by(|a, b| a > b));
+        array.reverse_in_place();
+        assert!(array.is_sorted_by(|a, b| a >= b));
+        assert!(!array![1.0f32, ::std::f32::NAN].is_sorted());
     }
 }
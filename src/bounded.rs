@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use tll::ternary::Nat;
+
+
+/// The `BoundedNat` type is a runtime `usize` carrying a compile-time guarantee that it is
+/// *strictly less than* the type-level natural `N`. The only ways to get one are through a checked
+/// constructor or from an operation which can prove the bound holds, so a `BoundedNat<L>` can be
+/// used to index into an `Array<L, T>` without any possibility of going out of bounds.
+///
+/// Results which may be as large as `L` itself (for example, the number of elements of an
+/// `Array<L, T>` matching some predicate) are given as a `BoundedNat<Succ<L>>`.
+pub struct BoundedNat<N: Nat> {
+    value: usize,
+    phantom: PhantomData<N>,
+}
+
+impl<N: Nat> BoundedNat<N> {
+    /// Wrap `value`, if it is less than `N`.
+    pub fn new(value: usize) -> Option<Self> {
+        if value < N::reify() {
+            Some(BoundedNat {
+                value: value,
+                phantom: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Wrap `value` without checking it against `N`. It is undefined behavior for `value` to be
+    /// greater than or equal to `N`.
+    pub unsafe fn new_unchecked(value: usize) -> Self {
+        BoundedNat {
+            value: value,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn get(&self) -> usize {
+        self.value
+    }
+}
+
+impl<N: Nat> From<BoundedNat<N>> for usize {
+    fn from(bounded: BoundedNat<N>) -> usize {
+        bounded.value
+    }
+}
+
+impl<N: Nat> Clone for BoundedNat<N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N: Nat> Copy for BoundedNat<N> {}
+
+impl<N: Nat> PartialEq for BoundedNat<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<N: Nat> Eq for BoundedNat<N> {}
+
+impl<N: Nat> PartialOrd for BoundedNat<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Nat> Ord for BoundedNat<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<N: Nat> Hash for BoundedNat<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<N: Nat> fmt::Debug for BoundedNat<N> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "BoundedNat({} < {})", self.value, N::reify())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tll::ternary::*;
+
+    #[test]
+    fn new() {
+        assert!(BoundedNat::<U0>::new(0).is_none());
+        assert_eq!(BoundedNat::<U1>::new(0).map(|n| n.get()), Some(0));
+        assert!(BoundedNat::<U1>::new(1).is_none());
+        assert_eq!(BoundedNat::<U8>::new(7).map(usize::from), Some(7));
+        assert!(BoundedNat::<U8>::new(8).is_none());
+    }
+}
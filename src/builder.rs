@@ -0,0 +1,85 @@
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+
+use array::Array;
+use storage::Arrayify;
+
+
+/// The `ArrayBuilder` type is an `Array` under construction. Elements are pushed onto it one at a
+/// time, and it keeps count of how many have been written so far. If it is dropped before it is
+/// finished - for example, because a closure supplied by the user panicked halfway through - only
+/// the elements which were actually written get dropped. This is what lets the various `Array`
+/// combinators write straight into uninitialized memory without leaking or double-dropping
+/// anything when something goes wrong partway through.
+pub struct ArrayBuilder<L: Arrayify<T>, T> {
+    data: MaybeUninit<Array<L, T>>,
+    len: usize,
+}
+
+impl<L: Arrayify<T>, T> ArrayBuilder<L, T> {
+    pub fn new() -> Self {
+        ArrayBuilder {
+            data: MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+
+    /// Build an `Array` out of the first `L::reify()` elements of an iterator. Panics if the
+    /// iterator runs out early; any elements past the first `L::reify()` are left untouched.
+    pub fn collect<I: IntoIterator<Item = T>>(iter: I) -> Array<L, T> {
+        let mut builder = ArrayBuilder::new();
+        let mut iter = iter.into_iter();
+        while !builder.is_full() {
+            match iter.next() {
+                Some(value) => builder.push(value),
+                None => {
+                    panic!("expected an iterator of length {}, but it ran out after {} elements",
+                           L::reify(),
+                           builder.len())
+                }
+            }
+        }
+        builder.finish()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == L::reify()
+    }
+
+    pub fn push(&mut self, value: T) {
+        assert!(!self.is_full(), "pushed onto an already full ArrayBuilder");
+        unsafe {
+            ptr::write(self.as_mut_ptr().offset(self.len as isize), value);
+        }
+        self.len += 1;
+    }
+
+    pub fn finish(self) -> Array<L, T> {
+        assert!(self.is_full(),
+                "finished an ArrayBuilder with only {} of {} elements",
+                self.len,
+                L::reify());
+        unsafe {
+            let data = ptr::read(&self.data);
+            mem::forget(self);
+            data.assume_init()
+        }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+}
+
+impl<L: Arrayify<T>, T> Drop for ArrayBuilder<L, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(slice::from_raw_parts_mut(self.as_mut_ptr(), self.len));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! The `codec` module implements `bincode`'s `Encode`, `Decode`, and `BorrowDecode` traits for
+//! `Array`, when the "bincode" feature is enabled.
+//!
+//! Since the length of an `Array` is carried in its type, there is no need to write it out: an
+//! `Array<L, T>` is encoded as exactly its `L` elements, one after another, with no length prefix.
+//! For fixed-size element types this means an `Array<U4, f32>` comes out as precisely 16 bytes,
+//! the same as a plain `[f32; 4]`.
+//!
+//! Each element still goes through its own `Encode` impl, though, so under `config::standard()`
+//! integers are varint-encoded and an `Array<U4, u32>` takes anywhere from 4 to 20 bytes depending
+//! on its contents. When the "bytemuck" feature is also enabled, wrapping the `Array` in a
+//! `PodArray` instead writes the elements out as their raw bytes, in one go, for any
+//! `T: bytemuck::Pod`. The size is then always exactly `L * size_of::<T>()`, but the bytes are in
+//! the machine's native byte order whatever the configuration says, so they should only be read
+//! back on a machine of the same endianness.
+
+use bincode::{BorrowDecode, Decode, Encode};
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+#[cfg(feature = "bytemuck")]
+use bincode::de::read::Reader;
+#[cfg(feature = "bytemuck")]
+use bincode::enc::write::Writer;
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+
+use array::Array;
+use builder::ArrayBuilder;
+use storage::Arrayify;
+
+
+impl<L: Arrayify<T>, T: Encode> Encode for Array<L, T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        for x in self.iter() {
+            x.encode(encoder)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Context, L: Arrayify<T>, T: Decode<Context>> Decode<Context> for Array<L, T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let mut builder = ArrayBuilder::new();
+        while !builder.is_full() {
+            builder.push(T::decode(decoder)?);
+        }
+        Ok(builder.finish())
+    }
+}
+
+impl<'de, Context, L: Arrayify<T>, T: BorrowDecode<'de, Context>> BorrowDecode<'de, Context>
+    for Array<L, T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(decoder: &mut D)
+                                                               -> Result<Self, DecodeError> {
+        let mut builder = ArrayBuilder::new();
+        while !builder.is_full() {
+            builder.push(T::borrow_decode(decoder)?);
+        }
+        Ok(builder.finish())
+    }
+}
+
+
+
+/// A wrapper around an `Array` of plain-old-data which encodes and decodes it as raw bytes, with
+/// no per-element encoding at all. See the module documentation for the caveat about byte order.
+#[cfg(feature = "bytemuck")]
+pub struct PodArray<L: Arrayify<T>, T>(pub Array<L, T>);
+
+#[cfg(feature = "bytemuck")]
+impl<L: Arrayify<T>, T: Pod> PodArray<L, T> {
+    fn decode_raw<D: Decoder>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let mut array: Array<L, T> = ArrayBuilder::collect(::std::iter::repeat(T::zeroed()));
+        {
+            let bytes = bytemuck::cast_slice_mut::<T, u8>(&mut array[..]);
+            decoder.claim_bytes_read(bytes.len())?;
+            decoder.reader().read(bytes)?;
+        }
+        Ok(PodArray(array))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<L: Arrayify<T>, T: Pod> Encode for PodArray<L, T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.writer().write(bytemuck::cast_slice::<T, u8>(&self.0[..]))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<Context, L: Arrayify<T>, T: Pod> Decode<Context> for PodArray<L, T> {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        PodArray::decode_raw(decoder)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'de, Context, L: Arrayify<T>, T: Pod> BorrowDecode<'de, Context> for PodArray<L, T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(decoder: &mut D)
+                                                               -> Result<Self, DecodeError> {
+        PodArray::decode_raw(decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tll::ternary::*;
+
+    use bincode::config;
+
+    #[test]
+    fn encode_f32_4() {
+        let array = array![1.0f32, 2.0, 3.0, 4.0];
+        let bytes = bincode::encode_to_vec(&array, config::standard()).unwrap();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes, bincode::encode_to_vec(&[1.0f32, 2.0, 3.0, 4.0], config::standard())
+            .unwrap());
+    }
+
+    #[test]
+    fn decode_f32_4() {
+        let array = array![1.0f32, 2.0, 3.0, 4.0];
+        let bytes = bincode::encode_to_vec(&array, config::standard()).unwrap();
+        let (decoded, read): (Array<U4, f32>, usize) =
+            bincode::decode_from_slice(&bytes, config::standard()).unwrap();
+        assert_eq!(read, 16);
+        assert_eq!(*decoded, *array);
+    }
+
+    #[test]
+    fn decode_too_short() {
+        let bytes = bincode::encode_to_vec(&array![1.0f32, 2.0, 3.0], config::standard()).unwrap();
+        let result: Result<(Array<U4, f32>, usize), _> =
+            bincode::decode_from_slice(&bytes, config::standard());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_strings() {
+        let array = array![String::from("foo"), String::from("bar")];
+        let bytes = bincode::encode_to_vec(&array, config::standard()).unwrap();
+        let (decoded, _): (Array<U2, String>, usize) =
+            bincode::decode_from_slice(&bytes, config::standard()).unwrap();
+        assert_eq!(*decoded, *array);
+    }
+
+    #[test]
+    fn encode_u32_varint() {
+        let small = bincode::encode_to_vec(&array![1u32, 2, 3, 4], config::standard()).unwrap();
+        assert_eq!(small.len(), 4);
+        let large = bincode::encode_to_vec(&array![1u32 << 31, 1 << 31, 1 << 31, 1 << 31],
+                                           config::standard())
+            .unwrap();
+        assert_eq!(large.len(), 20);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn encode_pod_fixed_size() {
+        let bytes = bincode::encode_to_vec(PodArray(array![1u32, 2, 3, 4]), config::standard())
+            .unwrap();
+        assert_eq!(bytes.len(), 16);
+        let bytes = bincode::encode_to_vec(PodArray(array![1u32 << 31, 1 << 31, 1 << 31, 1 << 31]),
+                                           config::standard())
+            .unwrap();
+        assert_eq!(bytes.len(), 16);
+        let bytes = bincode::encode_to_vec(PodArray(array![0u64, 1, ::std::u64::MAX]),
+                                           config::standard())
+            .unwrap();
+        assert_eq!(bytes.len(), 24);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn decode_pod_u64() {
+        let array = array![0u64, 1, ::std::u64::MAX];
+        let bytes = bincode::encode_to_vec(PodArray(array), config::standard()).unwrap();
+        let (decoded, read): (PodArray<U3, u64>, usize) =
+            bincode::decode_from_slice(&bytes, config::standard()).unwrap();
+        assert_eq!(read, 24);
+        assert_eq!(*decoded.0, *array);
+
+        let result: Result<(PodArray<U4, u64>, usize), _> =
+            bincode::decode_from_slice(&bytes, config::standard());
+        assert!(result.is_err());
+    }
+}
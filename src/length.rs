@@ -0,0 +1,130 @@
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use tll::ternary::Nat;
+
+use array::Array;
+use storage::Arrayify;
+
+
+/// The `TypedLength` type is a value-level token standing in for the type-level length `L`. It is
+/// useful as a bridge when a length is only settled on at runtime (say, read out of a
+/// configuration file at startup) but the rest of the program wants to work with `Array<L, T>`s:
+/// pick the right `TypedLength<L>` once, then use it to check and cast slices wherever they come
+/// from.
+pub struct TypedLength<L: Nat>(PhantomData<L>);
+
+impl<L: Nat> TypedLength<L> {
+    pub fn new() -> Self {
+        TypedLength(PhantomData)
+    }
+
+    /// The length this token stands for, as a runtime value.
+    pub fn value(&self) -> usize {
+        L::reify()
+    }
+
+    /// View a slice as an `Array<L, T>`, if it has exactly `L` elements.
+    pub fn check_slice<'a, T>(&self, s: &'a [T]) -> Option<&'a Array<L, T>>
+        where L: Arrayify<T>
+    {
+        if s.len() == L::reify() {
+            Some(unsafe { &*(s.as_ptr() as *const Array<L, T>) })
+        } else {
+            None
+        }
+    }
+
+    /// Mutably view a slice as an `Array<L, T>`, if it has exactly `L` elements.
+    pub fn check_slice_mut<'a, T>(&self, s: &'a mut [T]) -> Option<&'a mut Array<L, T>>
+        where L: Arrayify<T>
+    {
+        if s.len() == L::reify() {
+            Some(unsafe { &mut *(s.as_mut_ptr() as *mut Array<L, T>) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<L: Nat> Clone for TypedLength<L> {
+    fn clone(&self) -> Self {
+        TypedLength(PhantomData)
+    }
+}
+
+impl<L: Nat> Copy for TypedLength<L> {}
+
+impl<L: Nat> Default for TypedLength<L> {
+    fn default() -> Self {
+        TypedLength::new()
+    }
+}
+
+impl<L: Nat> fmt::Debug for TypedLength<L> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "TypedLength({})", L::reify())
+    }
+}
+
+
+
+/// The error given when a slice can't be converted into an `Array` because it has the wrong
+/// number of elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt,
+               "expected a slice of length {}, but it has length {}",
+               self.expected,
+               self.actual)
+    }
+}
+
+impl Error for LengthMismatch {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tll::ternary::*;
+
+    #[test]
+    fn value() {
+        assert_eq!(TypedLength::<U0>::new().value(), 0);
+        assert_eq!(TypedLength::<U8>::new().value(), 8);
+        assert_eq!(TypedLength::<U27>::new().value(), 27);
+    }
+
+    #[test]
+    fn check_slice() {
+        let v: Vec<i32> = (0..8).collect();
+        let array = TypedLength::<U8>::new().check_slice(&v).unwrap();
+        assert_eq!(array.len(), 8);
+        assert_eq!(array[7], 7);
+        assert!(TypedLength::<U7>::new().check_slice(&v).is_none());
+        assert!(TypedLength::<U9>::new().check_slice(&v).is_none());
+    }
+
+    #[test]
+    fn check_slice_mut() {
+        let mut v: Vec<i32> = (0..8).collect();
+        {
+            let array = TypedLength::<U8>::new().check_slice_mut(&mut v).unwrap();
+            array[0] = 42;
+        }
+        assert_eq!(v[0], 42);
+        assert!(TypedLength::<U3>::new().check_slice_mut(&mut v).is_none());
+    }
+
+    #[test]
+    fn length_mismatch_display() {
+        let err = LengthMismatch { expected: 8, actual: 3 };
+        assert_eq!(err.to_string(), "expected a slice of length 8, but it has length 3");
+    }
+}
@@ -13,18 +13,37 @@
 
 #![cfg_attr(feature = "specialization", feature(specialization))]
 
-extern crate unreachable;
-
 #[macro_use]
 extern crate type_operators;
 extern crate tll_iterator;
 
+#[cfg(feature = "bincode")]
+extern crate bincode;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "rand")]
+extern crate rand;
+
 /// This is `pub` for the benefit of exported macros. This way, they can refer to it as `$crate::tll`.
 pub extern crate type_level_logic as tll;
 
 #[macro_use]
 pub mod array;
-mod guillotine;
+mod bounded;
+mod builder;
+#[cfg(feature = "bincode")]
+mod codec;
+mod length;
+mod numeric;
+pub mod ops;
+pub mod permutation;
+#[cfg(feature = "rand")]
+mod random;
+pub mod reduce;
 mod storage;
 
 pub use array::*;
+pub use bounded::BoundedNat;
+#[cfg(all(feature = "bincode", feature = "bytemuck"))]
+pub use codec::PodArray;
+pub use length::{LengthMismatch, TypedLength};
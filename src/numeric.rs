@@ -0,0 +1,473 @@
+//! The `numeric` module holds the arithmetic and statistical operations on `Array`s - the ones
+//! which only make sense for numeric element types, as opposed to the general-purpose
+//! combinators living alongside the `Array` type itself.
+
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use tll::ternary::{NatPred, Pred};
+
+use array::Array;
+use builder::ArrayBuilder;
+use storage::Arrayify;
+
+
+impl<L: Arrayify<T>, T> Array<L, T> {
+    /// Compute, element by element, the shortest distance between `self` and `other` going either
+    /// way around a ring of circumference `period` - for example, the angle between two headings
+    /// in degrees, with `period` set to `360.0`. Every result lies between zero and half of
+    /// `period`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let a = array![350.0f64, 90.0];
+    /// let b = array![10.0f64, 180.0];
+    /// assert_eq!(*a.ring_distance(b, 360.0), [20.0, 90.0]);
+    /// # }
+    /// ```
+    pub fn ring_distance(self, other: Self, period: T) -> Self
+        where T: Sub<Output = T> + Rem<Output = T> + PartialOrd + Copy
+    {
+        self.zip_with(other, |a, b| {
+            let forward = (if a < b { b - a } else { a - b }) % period;
+            let backward = period - forward;
+            if backward < forward { backward } else { forward }
+        })
+    }
+
+    /// Scale the elements of the `Array` so that they sum to one, projecting it onto the
+    /// probability simplex. If the elements sum to zero, there is no sensible way to scale them,
+    /// and a uniform distribution - every element set to `1 / L` - is returned instead.
+    ///
+    /// `T: From<u8>` is asked for rather than `From<usize>` so that `f32` and `f64` qualify; the
+    /// `L` used for the uniform case is built up by adding ones together.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1.0f64, 1.0, 2.0].normalize_to_sum_one();
+    /// assert_eq!(*array, [0.25, 0.25, 0.5]);
+    /// # }
+    /// ```
+    pub fn normalize_to_sum_one(self) -> Self
+        where T: Add<Output = T> + Div<Output = T> + Default + Clone + PartialEq + From<u8>
+    {
+        let total = self.iter().cloned().fold(T::default(), |acc, x| acc + x);
+        if total == T::default() {
+            let len = (0..L::reify()).fold(T::default(), |acc, _| acc + T::from(1u8));
+            let share = T::from(1u8) / len;
+            self.map(|_| share.clone())
+        } else {
+            self.map(|x| x / total.clone())
+        }
+    }
+
+    /// Compute the mean of every prefix of the `Array`, so that element `i` of the result is the
+    /// average of elements `0` through `i` of the input.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![2.0f64, 4.0, 6.0].running_mean();
+    /// assert_eq!(*array, [2.0, 3.0, 4.0]);
+    /// # }
+    /// ```
+    pub fn running_mean(self) -> Array<L, f64>
+        where T: Into<f64>,
+              L: Arrayify<f64>
+    {
+        let mut total = 0.0;
+        let mut count = 0.0;
+        self.map(|x| {
+            total += x.into();
+            count += 1.0;
+            total / count
+        })
+    }
+
+    /// Compute the first-order finite differences of the `Array`, `[a[1] - a[0], a[2] - a[1], ...]`,
+    /// giving an `Array` one element shorter. This is the discrete derivative, and the inverse
+    /// of `cumsum`. Since every interior element takes part in two differences, `T: Clone` is
+    /// needed on top of `T: Sub`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert_eq!(*array![1i32, 3, 6, 10].diff(), [2, 3, 4]);
+    /// # }
+    /// ```
+    pub fn diff(self) -> Array<Pred<L>, T>
+        where T: Sub<Output = T> + Clone,
+              L: NatPred,
+              Pred<L>: Arrayify<T>
+    {
+        let mut builder = ArrayBuilder::new();
+        let mut iter = self.into_iter();
+        if let Some(mut prev) = iter.next() {
+            for next in iter {
+                builder.push(next.clone() - prev);
+                prev = next;
+            }
+        }
+        builder.finish()
+    }
+
+    /// Compute the prefix sums of the `Array`, `[a[0], a[0] + a[1], ...]`. This is the discrete
+    /// integral, and the inverse of `diff`. The first element is used as the starting point of
+    /// the sum, so no zero value is needed.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert_eq!(*array![1i32, 2, 3, 4].cumsum(), [1, 3, 6, 10]);
+    /// # }
+    /// ```
+    pub fn cumsum(self) -> Self
+        where T: Add<Output = T> + Clone
+    {
+        let mut total = None;
+        self.map(|x| {
+            let next = match total.take() {
+                Some(total) => total + x,
+                None => x,
+            };
+            total = Some(next.clone());
+            next
+        })
+    }
+
+    /// Compute the circular autocorrelation of the `Array`, where element `k` of the result is
+    /// `sum(self[i] * self[(i + k) % L])` over all `i`. This is done directly, in `O(L^2)` time.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2, 3];
+    /// assert_eq!(*array.circular_autocorrelation(), [14, 11, 11]);
+    /// # }
+    /// ```
+    pub fn circular_autocorrelation(&self) -> Self
+        where T: Mul<Output = T> + Add<Output = T> + Default + Clone
+    {
+        let len = L::reify();
+        ArrayBuilder::collect((0..len).map(|k| {
+            (0..len).fold(T::default(),
+                          |acc, i| acc + self[i].clone() * self[(i + k) % len].clone())
+        }))
+    }
+
+    /// Compute the circular convolution of the `Array` with `kernel`, wrapping indices around
+    /// modulo `L` so that the result is the same length as the input. Element `n` of the result is
+    /// `sum(kernel[m] * self[(n - m) % L])` over all `m`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![1i32, 2, 3, 4];
+    /// let impulse = array![0i32, 1];
+    /// assert_eq!(*array.convolve_circular(impulse), [4, 1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn convolve_circular<M>(self, kernel: Array<M, T>) -> Self
+        where T: Mul<Output = T> + Add<Output = T> + Default + Clone,
+              M: Arrayify<T>
+    {
+        let len = L::reify();
+        ArrayBuilder::collect((0..len).map(|n| {
+            kernel.iter().enumerate().fold(T::default(), |acc, (m, k)| {
+                acc + k.clone() * self[(n + len - m % len) % len].clone()
+            })
+        }))
+    }
+
+    /// Add up the elements of the `Array`, starting from `T::default()` - so an empty `Array`
+    /// sums to zero.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert_eq!(array![1i32, 2, 3, 4].sum(), 10);
+    /// # }
+    /// ```
+    pub fn sum(self) -> T
+        where T: Add<Output = T> + Default
+    {
+        self.fold(T::default(), |acc, x| acc + x)
+    }
+
+    /// Multiply together the elements of the `Array`, starting from one - so an empty `Array` has
+    /// a product of one. As with `normalize_to_sum_one`, the one is had through `T: From<u8>`.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// assert_eq!(array![1i32, 2, 3, 4].product(), 24);
+    /// # }
+    /// ```
+    pub fn product(self) -> T
+        where T: Mul<Output = T> + From<u8>
+    {
+        self.fold(T::from(1u8), |acc, x| acc * x)
+    }
+
+    /// Add up the elements of the `Array` without consuming it.
+    pub fn sum_ref(&self) -> T
+        where T: Add<Output = T> + Default + Copy
+    {
+        self.iter().fold(T::default(), |acc, &x| acc + x)
+    }
+
+    /// Multiply together the elements of the `Array` without consuming it.
+    pub fn product_ref(&self) -> T
+        where T: Mul<Output = T> + From<u8> + Copy
+    {
+        self.iter().fold(T::from(1u8), |acc, &x| acc * x)
+    }
+}
+
+
+// The element-wise binary operators, for every combination of owned and borrowed operands. A
+// borrowed operand is first cloned into an owned `Array` with `map_ref`, so that every case comes
+// down to `zip_with`.
+macro_rules! elementwise_binops {
+    ($($Op:ident $op:ident),*) => ($(
+        impl<L: Arrayify<T>, T: $Op<Output = T>> $Op for Array<L, T> {
+            type Output = Array<L, T>;
+
+            fn $op(self, other: Array<L, T>) -> Array<L, T> {
+                self.zip_with(other, $Op::$op)
+            }
+        }
+
+        impl<'a, L: Arrayify<T>, T: $Op<Output = T> + Clone> $Op<&'a Array<L, T>> for Array<L, T> {
+            type Output = Array<L, T>;
+
+            fn $op(self, other: &'a Array<L, T>) -> Array<L, T> {
+                self.zip_with(other.map_ref(T::clone), $Op::$op)
+            }
+        }
+
+        impl<'a, L: Arrayify<T>, T: $Op<Output = T> + Clone> $Op<Array<L, T>> for &'a Array<L, T> {
+            type Output = Array<L, T>;
+
+            fn $op(self, other: Array<L, T>) -> Array<L, T> {
+                self.map_ref(T::clone).zip_with(other, $Op::$op)
+            }
+        }
+
+        impl<'a, 'b, L: Arrayify<T>, T: $Op<Output = T> + Clone> $Op<&'b Array<L, T>>
+            for &'a Array<L, T> {
+            type Output = Array<L, T>;
+
+            fn $op(self, other: &'b Array<L, T>) -> Array<L, T> {
+                self.map_ref(T::clone).zip_with(other.map_ref(T::clone), $Op::$op)
+            }
+        }
+    )*);
+}
+
+elementwise_binops!(Add add, Sub sub);
+
+
+impl<L: Arrayify<T>, T: Neg<Output = T>> Neg for Array<L, T> {
+    type Output = Array<L, T>;
+
+    fn neg(self) -> Array<L, T> {
+        self.map(Neg::neg)
+    }
+}
+
+impl<'a, L: Arrayify<T>, T: Neg<Output = T> + Clone> Neg for &'a Array<L, T> {
+    type Output = Array<L, T>;
+
+    fn neg(self) -> Array<L, T> {
+        ArrayBuilder::collect(self.iter().map(|x| -x.clone()))
+    }
+}
+
+impl<L: Arrayify<f32>> Array<L, f32> {
+    /// Compute the softmax of the `Array`, `exp(x[i]) / sum(exp(x[j]))`, turning arbitrary scores
+    /// into a probability distribution. The maximum is subtracted from every element before
+    /// exponentiating, which leaves the result unchanged mathematically but keeps large inputs
+    /// from overflowing to infinity.
+    ///
+    /// ```
+    /// # #[macro_use] extern crate tll_array; fn main() {
+    /// let array = array![0.0f32, 0.0].softmax();
+    /// assert_eq!(*array, [0.5, 0.5]);
+    /// # }
+    /// ```
+    pub fn softmax(self) -> Self {
+        let max = self.iter().cloned().fold(::std::f32::NEG_INFINITY, f32::max);
+        let exps = self.map(|x| (x - max).exp());
+        let total: f32 = exps.iter().sum();
+        exps.map(|x| x / total)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ring_distance_degrees() {
+        let a = array![350.0f64, 10.0, 0.0, 180.0];
+        let b = array![10.0f64, 350.0, 0.0, 0.0];
+        assert_eq!(*a.ring_distance(b, 360.0), [20.0, 20.0, 0.0, 180.0]);
+    }
+
+    #[test]
+    fn ring_distance_integers() {
+        let a = array![1u32, 6, 3];
+        let b = array![6u32, 1, 10];
+        assert_eq!(*a.ring_distance(b, 7), [2, 2, 0]);
+    }
+
+    #[test]
+    fn normalize_to_sum_one_8() {
+        let array = array![3.0f64, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0].normalize_to_sum_one();
+        let total: f64 = array.iter().sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert!((array[5] - 9.0 / 31.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn normalize_to_sum_one_zero_sum() {
+        let array = array![0.0f32, 0.0, 0.0, 0.0].normalize_to_sum_one();
+        assert_eq!(*array, [0.25, 0.25, 0.25, 0.25]);
+
+        let array = array![1.0f64, -1.0, 0.0].normalize_to_sum_one();
+        let total: f64 = array.iter().sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn running_mean_3() {
+        assert_eq!(*array![2.0f64, 4.0, 6.0].running_mean(), [2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn running_mean_integers() {
+        assert_eq!(*array![1i32, 2, 3, 4, 5, 6].running_mean(),
+                   [1.0, 1.5, 2.0, 2.5, 3.0, 3.5]);
+    }
+
+    #[test]
+    fn diff_1() {
+        assert_eq!(array![42i32].diff().len(), 0);
+    }
+
+    #[test]
+    fn diff_4() {
+        assert_eq!(*array![1i32, 3, 6, 10].diff(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn diff_10() {
+        let array = array![0.5f64, 1.0, 0.0, 2.0, 2.0, 2.5, 1.5, 4.0, 0.0, 8.0];
+        assert_eq!(*array.diff(), [0.5, -1.0, 2.0, 0.0, 0.5, -1.0, 2.5, -4.0, 8.0]);
+    }
+
+    #[test]
+    fn cumsum_4() {
+        assert_eq!(*array![1i32, 2, 3, 4].cumsum(), [1, 3, 6, 10]);
+    }
+
+    #[test]
+    fn cumsum_diff_inverse() {
+        let array = array![3i32, 1, 4, 1, 5, 9, 2, 6];
+        assert_eq!(*array.cumsum().diff(), array[1..]);
+    }
+
+    #[test]
+    fn circular_autocorrelation_lag_0() {
+        let array = array![3i32, 1, 4, 1, 5, 9, 2, 6];
+        let squares: i32 = array.iter().map(|x| x * x).sum();
+        assert_eq!(array.circular_autocorrelation()[0], squares);
+    }
+
+    #[test]
+    fn circular_autocorrelation_symmetric() {
+        let array = array![3.0f64, 1.0, 4.0, 1.0, 5.0];
+        let correlation = array.circular_autocorrelation();
+        for k in 1..5 {
+            assert_eq!(correlation[k], correlation[5 - k]);
+        }
+    }
+
+    #[test]
+    fn convolve_circular_impulse() {
+        let array = array![42i32, 84, 126, 168];
+        let impulse = array![0i32, 0, 1, 0];
+        assert_eq!(*array.convolve_circular(impulse), [126, 168, 42, 84]);
+    }
+
+    #[test]
+    fn convolve_circular_long_kernel() {
+        let array = array![1i32, 2, 3];
+        let kernel = array![1i32, 0, 0, 1, 0];
+        assert_eq!(*array.convolve_circular(kernel), [2, 4, 6]);
+    }
+
+    #[test]
+    fn convolve_circular_smoothing() {
+        let array = array![0.0f64, 3.0, 0.0, 0.0, 0.0, 3.0];
+        let kernel = array![1.0f64 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+        let smoothed = array.convolve_circular(kernel);
+        for (x, y) in smoothed.iter().zip([1.0, 2.0, 1.0, 1.0, 0.0, 1.0].iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn softmax_8() {
+        let array = array![3.0f32, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0].softmax();
+        let total: f32 = array.iter().sum();
+        assert!((total - 1.0).abs() < 1e-6);
+        assert!(array[5] > array[7] && array[7] > array[4] && array[4] > array[2]);
+        assert_eq!(array[1], array[3]);
+    }
+
+    #[test]
+    fn softmax_monotonic() {
+        let array = array![-2.0f32, -1.0, 0.0, 0.5, 1.0, 10.0].softmax();
+        for pair in array.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+    }
+
+    #[test]
+    fn softmax_large() {
+        let array = array![1000.0f32, 1000.0, 1000.0, 1000.0].softmax();
+        assert_eq!(*array, [0.25, 0.25, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn sum_product_8() {
+        let array = array![3i32, 1, 4, 1, 5, 9, 2, 6];
+        assert_eq!(array.sum_ref(), 31);
+        assert_eq!(array.product_ref(), 6480);
+        assert_eq!(array.sum(), 31);
+        assert_eq!(array.product(), 6480);
+    }
+
+    #[test]
+    fn sum_product_empty() {
+        let array: ::Array<::tll::ternary::U0, f64> = array![];
+        assert_eq!(array.sum_ref(), 0.0);
+        assert_eq!(array.product_ref(), 1.0);
+    }
+
+    #[test]
+    fn add_sub_operand_combinations() {
+        let a = array![1i32, 2, 3];
+        let b = array![10i32, 20, 30];
+        assert_eq!(*(a + b), [11, 22, 33]);
+        assert_eq!(*(&a + &b), [11, 22, 33]);
+        assert_eq!(*(a + &b), [11, 22, 33]);
+        assert_eq!(*(&a + b), [11, 22, 33]);
+
+        assert_eq!(*(b - a), [9, 18, 27]);
+        assert_eq!(*(&b - &a), [9, 18, 27]);
+        assert_eq!(*(b - &a), [9, 18, 27]);
+        assert_eq!(*(&a - b), [-9, -18, -27]);
+    }
+
+    #[test]
+    fn neg_3() {
+        let a = array![1.5f64, -2.0, 0.0];
+        assert_eq!(*-&a, [-1.5, 2.0, -0.0]);
+        assert_eq!(-(-a), a);
+        assert_eq!(a + -a, array![0.0; ::tll::ternary::U3]);
+    }
+}
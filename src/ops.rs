@@ -0,0 +1,522 @@
+//! The `ops` module contains type-level arithmetic on the ternary naturals from `type-level-logic`,
+//! for use in describing how operations on `Array`s change their lengths.
+//!
+//! All of these operators work digit-by-digit on the ternary representation, least significant
+//! digit first, carrying or borrowing into the next digit up exactly as you would on paper. The
+//! results are always kept in canonical form: a `Zero` digit is only ever placed on top of a
+//! nonzero number (through `Triple`), so that `Diff<U8, U3>` comes out as *the same type* as `U5`
+//! rather than some equal-valued type with a leading zero digit. This matters, since otherwise
+//! the compiler would refuse to unify an `Array<Diff<U8, U3>, T>` with an `Array<U5, T>`.
+//!
+//! Operators which are undefined for some inputs simply have no implementation there. For
+//! example, `Diff<M, N>` with `M < N` would have to borrow past the most significant digit, and
+//! `Pred<Term>` does not exist, so `M: NatDiff<N>` does not hold and any use of it fails to
+//! compile. This makes `M: NatDiff<N>` double as a proof that `N <= M`.
+
+use tll::ternary::{Nat, Term, Zero, One, Two, NatPred, Succ, NatSucc, Triple, NatTriple};
+
+
+type_operators! {
+    [A, B, C, D, E, F, G, H]
+
+    (Sum) NatSum(Nat, Nat): Nat {
+        forall (N: Nat) {
+            [N, Term] => N
+            [Term, (Zero N)] => (Zero N)
+            [Term, (One N)] => (One N)
+            [Term, (Two N)] => (Two N)
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), (Zero N)] => (@NatTriple (# M N))
+            [(Zero M), (One N)] => (One (# M N))
+            [(Zero M), (Two N)] => (Two (# M N))
+            [(One M), (Zero N)] => (One (# M N))
+            [(One M), (One N)] => (Two (# M N))
+            [(One M), (Two N)] => (@NatTriple (# (# M N) (One Term)))
+            [(Two M), (Zero N)] => (Two (# M N))
+            [(Two M), (One N)] => (@NatTriple (# (# M N) (One Term)))
+            [(Two M), (Two N)] => (One (# (# M N) (One Term)))
+        }
+    }
+
+    (Diff) NatDiff(Nat, Nat): Nat {
+        forall (N: Nat) {
+            [N, Term] => N
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), (Zero N)] => (@NatTriple (# M N))
+            [(Zero M), (One N)] => (Two (@NatPred (# M N)))
+            [(Zero M), (Two N)] => (One (@NatPred (# M N)))
+            [(One M), (Zero N)] => (One (# M N))
+            [(One M), (One N)] => (@NatTriple (# M N))
+            [(One M), (Two N)] => (Two (@NatPred (# M N)))
+            [(Two M), (Zero N)] => (Two (# M N))
+            [(Two M), (One N)] => (One (# M N))
+            [(Two M), (Two N)] => (@NatTriple (# M N))
+        }
+    }
+
+    (Product) NatProduct(Nat, Nat): Nat {
+        forall (N: Nat) {
+            [Term, N] => Term
+        }
+        forall (M: Nat, N: Nat) {
+            [(Zero M), N] => (@NatTriple (# M N))
+            [(One M), N] => (@NatSum (@NatTriple (# M N)) N)
+            [(Two M), N] => (@NatSum (@NatSum (@NatTriple (# M N)) N) N)
+        }
+    }
+}
+
+
+/// The result of comparing two type-level naturals with `Compare`: the first is less than the
+/// second.
+pub struct Less;
+
+/// The result of comparing two type-level naturals with `Compare`: they are equal.
+pub struct Equal;
+
+/// The result of comparing two type-level naturals with `Compare`: the first is greater than the
+/// second.
+pub struct Greater;
+
+
+/// Combine the comparison of the more significant digits of two naturals (`Self`) with the
+/// comparison of their least significant digits (`C`): the more significant digits decide, unless
+/// they are equal.
+pub trait ThenCompare<C> {
+    type Output;
+}
+
+impl<C> ThenCompare<C> for Less {
+    type Output = Less;
+}
+
+impl<C> ThenCompare<C> for Equal {
+    type Output = C;
+}
+
+impl<C> ThenCompare<C> for Greater {
+    type Output = Greater;
+}
+
+
+/// Compare two type-level naturals, giving one of `Less`, `Equal`, or `Greater`.
+pub trait NatCompare<N: Nat>: Nat {
+    type Output;
+}
+
+pub type Compare<M, N> = <M as NatCompare<N>>::Output;
+
+impl NatCompare<Term> for Term {
+    type Output = Equal;
+}
+
+macro_rules! compare_with_term {
+    ($($digit:ident),*) => ($(
+        impl<N: Nat> NatCompare<$digit<N>> for Term {
+            type Output = Less;
+        }
+
+        impl<M: Nat> NatCompare<Term> for $digit<M> {
+            type Output = Greater;
+        }
+    )*);
+}
+
+compare_with_term!(Zero, One, Two);
+
+macro_rules! compare_digits {
+    ($($a:ident $b:ident => $c:ident),*) => ($(
+        impl<M: NatCompare<N>, N: Nat> NatCompare<$b<N>> for $a<M>
+            where Compare<M, N>: ThenCompare<$c>
+        {
+            type Output = <Compare<M, N> as ThenCompare<$c>>::Output;
+        }
+    )*);
+}
+
+compare_digits! {
+    Zero Zero => Equal, Zero One => Less, Zero Two => Less,
+    One Zero => Greater, One One => Equal, One Two => Less,
+    Two Zero => Greater, Two One => Greater, Two Two => Equal
+}
+
+
+/// The `NatLe` trait is implemented for the type-level naturals less than or equal to `N`: those
+/// which can be subtracted from `N`.
+pub trait NatLe<N: Nat>: Nat {}
+
+impl<M: Nat, N: NatDiff<M>> NatLe<N> for M {}
+
+
+/// The `NatLt` trait is implemented for the type-level naturals strictly less than `N`, for
+/// example to prove that a type-level index is in bounds for an `Array<N, T>`.
+pub trait NatLt<N: Nat>: NatCompare<N, Output = Less> {}
+
+impl<M: NatCompare<N, Output = Less>, N: Nat> NatLt<N> for M {}
+
+
+/// The `NatValue` trait gives the value of a type-level natural as an associated constant. This
+/// is the same number as `Nat::reify()`, but usable in constant expressions.
+pub trait NatValue: Nat {
+    const VALUE: usize;
+}
+
+impl NatValue for Term {
+    const VALUE: usize = 0;
+}
+
+impl<N: NatValue> NatValue for Zero<N> {
+    const VALUE: usize = 3 * N::VALUE;
+}
+
+impl<N: NatValue> NatValue for One<N> {
+    const VALUE: usize = 3 * N::VALUE + 1;
+}
+
+impl<N: NatValue> NatValue for Two<N> {
+    const VALUE: usize = 3 * N::VALUE + 2;
+}
+
+
+/// The `IsZero` trait is implemented only for `Term`, the type-level zero.
+pub trait IsZero: Nat {}
+
+impl IsZero for Term {}
+
+/// The `IsNonZero` trait is implemented for every type-level natural except zero, so that a bound
+/// of `L: IsNonZero` guarantees that an `Array<L, T>` has at least one element. In canonical form
+/// a `One` or `Two` digit anywhere makes a natural nonzero, while a `Zero` digit needs something
+/// nonzero above it.
+pub trait IsNonZero: Nat {}
+
+impl<N: IsNonZero> IsNonZero for Zero<N> {}
+impl<N: Nat> IsNonZero for One<N> {}
+impl<N: Nat> IsNonZero for Two<N> {}
+
+
+/// One step of long division: find how many times (`Digit`, counting up from `D`) the divisor `N`
+/// goes into `Self`, and what is left over (`Rem`), given that `C` is the result of comparing
+/// `Self` to `N`. This is done by repeated subtraction; since the shifted remainder in a step of
+/// long division is always less than `3 * N`, it never takes more than two.
+pub trait NatDivStep<N: Nat, C, D: Nat>: Nat {
+    type Digit: Nat;
+    type Rem: Nat;
+}
+
+impl<X: Nat, N: Nat, D: Nat> NatDivStep<N, Less, D> for X {
+    type Digit = D;
+    type Rem = X;
+}
+
+impl<X: Nat, N: Nat, D: NatSucc> NatDivStep<N, Equal, D> for X {
+    type Digit = Succ<D>;
+    type Rem = Term;
+}
+
+impl<X, N: Nat, D: NatSucc> NatDivStep<N, Greater, D> for X
+    where X: NatDiff<N>,
+          Diff<X, N>: NatCompare<N>,
+          Diff<X, N>: NatDivStep<N, Compare<Diff<X, N>, N>, Succ<D>>
+{
+    type Digit = <Diff<X, N> as NatDivStep<N, Compare<Diff<X, N>, N>, Succ<D>>>::Digit;
+    type Rem = <Diff<X, N> as NatDivStep<N, Compare<Diff<X, N>, N>, Succ<D>>>::Rem;
+}
+
+
+/// Divide one type-level natural by another, nonzero one, giving both the quotient and the
+/// remainder. This is schoolbook long division: the quotient and remainder of everything but the
+/// least significant digit are computed first, and then the remainder is shifted up a digit, the
+/// least significant digit brought down, and a single `NatDivStep` performed.
+pub trait NatDivMod<N: NatPred>: Nat {
+    type Quot: Nat;
+    type Rem: Nat;
+}
+
+pub type Quotient<M, N> = <M as NatDivMod<N>>::Quot;
+pub type Remainder<M, N> = <M as NatDivMod<N>>::Rem;
+
+impl<N: NatPred> NatDivMod<N> for Term {
+    type Quot = Term;
+    type Rem = Term;
+}
+
+/// The remainder carried down from the more significant digits, shifted up a digit, with the
+/// digit `D` brought down into its place.
+type BroughtDown<M, N, D> = Sum<Triple<Remainder<M, N>>, D>;
+
+macro_rules! div_mod_digits {
+    ($($digit:ident => $d:ty),*) => ($(
+        impl<M, N: NatPred> NatDivMod<N> for $digit<M>
+            where M: NatDivMod<N>,
+                  Remainder<M, N>: NatTriple,
+                  Triple<Remainder<M, N>>: NatSum<$d>,
+                  BroughtDown<M, N, $d>: NatCompare<N>,
+                  BroughtDown<M, N, $d>: NatDivStep<N, Compare<BroughtDown<M, N, $d>, N>, Term>,
+                  Quotient<M, N>: NatTriple,
+                  Triple<Quotient<M, N>>:
+                      NatSum<<BroughtDown<M, N, $d> as
+                              NatDivStep<N, Compare<BroughtDown<M, N, $d>, N>, Term>>::Digit>
+        {
+            type Quot = Sum<Triple<Quotient<M, N>>,
+                            <BroughtDown<M, N, $d> as
+                             NatDivStep<N, Compare<BroughtDown<M, N, $d>, N>, Term>>::Digit>;
+            type Rem = <BroughtDown<M, N, $d> as
+                        NatDivStep<N, Compare<BroughtDown<M, N, $d>, N>, Term>>::Rem;
+        }
+    )*);
+}
+
+div_mod_digits!(Zero => Term, One => One<Term>, Two => Two<Term>);
+
+
+/// The `IsEven` trait is implemented for exactly the even type-level naturals, as those which
+/// leave no remainder when divided by two. Its half can be had with `Quotient<N, U2>`.
+pub trait IsEven: NatDivMod<Two<Term>, Rem = Term> {}
+
+impl<N: NatDivMod<Two<Term>, Rem = Term>> IsEven for N {}
+
+
+/// The `IsPowerOfTwo` trait is implemented for exactly the type-level naturals which are powers of
+/// two: one, and those even naturals whose half is a power of two.
+pub trait IsPowerOfTwo: Nat {}
+
+impl<N: NatCompare<One<Term>>> IsPowerOfTwo for N where N: PowerOfTwoBy<Compare<N, One<Term>>> {}
+
+/// The recursion behind `IsPowerOfTwo`, split up by `C`, the result of comparing `Self` to one.
+pub trait PowerOfTwoBy<C>: Nat {}
+
+impl<N: Nat> PowerOfTwoBy<Equal> for N {}
+
+impl<N: IsEven> PowerOfTwoBy<Greater> for N where Quotient<N, Two<Term>>: IsPowerOfTwo {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tll::ternary::*;
+
+    use std::marker::PhantomData;
+
+    fn assert_same<N>(_: PhantomData<N>, _: PhantomData<N>) {}
+
+    #[test]
+    fn sum_reify() {
+        assert_eq!(<Sum<U0, U0> as Nat>::reify(), 0);
+        assert_eq!(<Sum<U0, U5> as Nat>::reify(), 5);
+        assert_eq!(<Sum<U5, U0> as Nat>::reify(), 5);
+        assert_eq!(<Sum<U2, U2> as Nat>::reify(), 4);
+        assert_eq!(<Sum<U8, U1> as Nat>::reify(), 9);
+        assert_eq!(<Sum<U26, U1> as Nat>::reify(), 27);
+    }
+
+    // Every pair of operands is checked both for the value of the sum, and for it being in
+    // canonical form - by subtracting one operand back off and comparing types with the other.
+    macro_rules! check_sums {
+        ([$($m:ident),*]; $ns:tt) => ($(check_sums!(@with $m; $ns);)*);
+        (@with $m:ident; [$($n:ident),*]) => ($(
+            assert_eq!(<Sum<$m, $n> as Nat>::reify(), $m::reify() + $n::reify());
+            assert_same(PhantomData::<Diff<Sum<$m, $n>, $n>>, PhantomData::<$m>);
+        )*);
+    }
+
+    #[test]
+    fn sum_exhaustive() {
+        check_sums!([
+            U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16, U17, U18,
+            U19, U20, U21, U22, U23, U24, U25, U26, U27, U28, U29, U30, U31, U32, U33, U34, U35,
+            U36, U37, U38, U39, U40, U41, U42, U43, U44, U45, U46, U47, U48, U49, U50, U51, U52,
+            U53, U54, U55, U56, U57, U58, U59, U60, U61, U62, U63
+        ]; [
+            U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11, U12, U13, U14, U15, U16, U17, U18,
+            U19, U20, U21, U22, U23, U24, U25, U26, U27, U28, U29, U30, U31, U32, U33, U34, U35,
+            U36, U37, U38, U39, U40, U41, U42, U43, U44, U45, U46, U47, U48, U49, U50, U51, U52,
+            U53, U54, U55, U56, U57, U58, U59, U60, U61, U62, U63
+        ]);
+    }
+
+    #[test]
+    fn sum_canonical() {
+        assert_same(PhantomData::<Sum<U2, U1>>, PhantomData::<U3>);
+        assert_same(PhantomData::<Sum<U8, U1>>, PhantomData::<U9>);
+        assert_same(PhantomData::<Sum<U13, U14>>, PhantomData::<U27>);
+    }
+
+    #[test]
+    fn product_reify() {
+        assert_eq!(<Product<U0, U7> as Nat>::reify(), 0);
+        assert_eq!(<Product<U7, U0> as Nat>::reify(), 0);
+        assert_eq!(<Product<U1, U7> as Nat>::reify(), 7);
+        assert_eq!(<Product<U2, U3> as Nat>::reify(), 6);
+        assert_eq!(<Product<U5, U5> as Nat>::reify(), 25);
+        assert_eq!(<Product<U8, U7> as Nat>::reify(), 56);
+    }
+
+    #[test]
+    fn product_canonical() {
+        assert_same(PhantomData::<Product<U2, U3>>, PhantomData::<U6>);
+        assert_same(PhantomData::<Product<U3, U3>>, PhantomData::<U9>);
+        assert_same(PhantomData::<Product<U4, U4>>, PhantomData::<U16>);
+    }
+
+    // The products past `U63` are spelled out digit by digit, least significant first; `U81` is
+    // `10000` in ternary.
+    #[test]
+    fn product_carries() {
+        type U81 = Zero<Zero<Zero<Zero<One<Term>>>>>;
+        type U64 = One<Zero<One<Two<Term>>>>;
+        type U169 = One<Two<Zero<Zero<Two<Term>>>>>;
+
+        assert_same(PhantomData::<Product<U9, U9>>, PhantomData::<U81>);
+        assert_same(PhantomData::<Product<U8, U8>>, PhantomData::<U64>);
+        assert_same(PhantomData::<Product<U13, U13>>, PhantomData::<U169>);
+        assert_same(PhantomData::<Product<U26, U2>>, PhantomData::<U52>);
+        assert_same(PhantomData::<Product<U7, U9>>, PhantomData::<U63>);
+        assert_eq!(<Product<U26, U26> as Nat>::reify(), 676);
+        assert_eq!(<Product<U62, U62> as Nat>::reify(), 3844);
+    }
+
+    #[test]
+    fn compare() {
+        assert_same(PhantomData::<Compare<U0, U0>>, PhantomData::<Equal>);
+        assert_same(PhantomData::<Compare<U0, U1>>, PhantomData::<Less>);
+        assert_same(PhantomData::<Compare<U5, U3>>, PhantomData::<Greater>);
+        assert_same(PhantomData::<Compare<U8, U9>>, PhantomData::<Less>);
+        assert_same(PhantomData::<Compare<U11, U19>>, PhantomData::<Less>);
+        assert_same(PhantomData::<Compare<U27, U27>>, PhantomData::<Equal>);
+        assert_same(PhantomData::<Compare<U28, U26>>, PhantomData::<Greater>);
+    }
+
+    fn assert_le<M: NatLe<N>, N: Nat>() {}
+
+    #[test]
+    fn le() {
+        assert_le::<U0, U0>();
+        assert_le::<U0, U1>();
+        assert_le::<U3, U3>();
+        assert_le::<U7, U8>();
+        assert_le::<U26, U27>();
+    }
+
+    fn assert_lt<M: NatLt<N>, N: Nat>() {}
+
+    #[test]
+    fn lt() {
+        assert_lt::<U0, U1>();
+        assert_lt::<U2, U3>();
+        assert_lt::<U7, U8>();
+        assert_lt::<U0, U27>();
+        assert_lt::<U26, U27>();
+    }
+
+    #[test]
+    fn div_mod_reify() {
+        assert_eq!(<Quotient<U0, U3> as Nat>::reify(), 0);
+        assert_eq!(<Remainder<U0, U3> as Nat>::reify(), 0);
+        assert_eq!(<Quotient<U10, U3> as Nat>::reify(), 3);
+        assert_eq!(<Remainder<U10, U3> as Nat>::reify(), 1);
+        assert_eq!(<Quotient<U7, U1> as Nat>::reify(), 7);
+        assert_eq!(<Remainder<U7, U1> as Nat>::reify(), 0);
+        assert_eq!(<Quotient<U5, U8> as Nat>::reify(), 0);
+        assert_eq!(<Remainder<U5, U8> as Nat>::reify(), 5);
+        assert_eq!(<Quotient<U63, U2> as Nat>::reify(), 31);
+        assert_eq!(<Remainder<U63, U2> as Nat>::reify(), 1);
+        assert_eq!(<Quotient<U62, U7> as Nat>::reify(), 8);
+        assert_eq!(<Remainder<U62, U7> as Nat>::reify(), 6);
+    }
+
+    #[test]
+    fn div_mod_canonical() {
+        assert_same(PhantomData::<Quotient<U10, U3>>, PhantomData::<U3>);
+        assert_same(PhantomData::<Remainder<U10, U3>>, PhantomData::<U1>);
+        assert_same(PhantomData::<Quotient<U18, U2>>, PhantomData::<U9>);
+        assert_same(PhantomData::<Remainder<U18, U2>>, PhantomData::<U0>);
+        assert_same(PhantomData::<Quotient<U9, U9>>, PhantomData::<U1>);
+    }
+
+    #[test]
+    fn nat_value() {
+        const NINE: usize = <U9 as NatValue>::VALUE;
+        assert_eq!(NINE, 9);
+        assert_eq!(<U0 as NatValue>::VALUE, 0);
+        assert_eq!(<U26 as NatValue>::VALUE, 26);
+        assert_eq!(<U63 as NatValue>::VALUE, U63::reify());
+        assert_eq!(<Product<U9, U9> as NatValue>::VALUE, 81);
+    }
+
+    fn assert_zero<N: IsZero>() {}
+    fn assert_nonzero<N: IsNonZero>() {}
+
+    #[test]
+    fn is_zero() {
+        assert_zero::<U0>();
+        assert_zero::<Diff<U9, U9>>();
+        assert_nonzero::<U1>();
+        assert_nonzero::<U3>();
+        assert_nonzero::<U9>();
+        assert_nonzero::<U27>();
+        assert_nonzero::<U62>();
+        assert_nonzero::<Product<U9, U9>>();
+    }
+
+    fn assert_even<N: IsEven>() {}
+
+    #[test]
+    fn is_even() {
+        assert_even::<U0>();
+        assert_even::<U2>();
+        assert_even::<U8>();
+        assert_even::<U18>();
+        assert_even::<U62>();
+    }
+
+    fn assert_power_of_two<N: IsPowerOfTwo>() {}
+
+    #[test]
+    fn is_power_of_two() {
+        assert_power_of_two::<U1>();
+        assert_power_of_two::<U2>();
+        assert_power_of_two::<U4>();
+        assert_power_of_two::<U8>();
+        assert_power_of_two::<U16>();
+        assert_power_of_two::<U32>();
+    }
+
+    #[test]
+    fn diff_reify() {
+        assert_eq!(<Diff<U0, U0> as Nat>::reify(), 0);
+        assert_eq!(<Diff<U1, U0> as Nat>::reify(), 1);
+        assert_eq!(<Diff<U1, U1> as Nat>::reify(), 0);
+        assert_eq!(<Diff<U8, U3> as Nat>::reify(), 5);
+        assert_eq!(<Diff<U10, U2> as Nat>::reify(), 8);
+        assert_eq!(<Diff<U27, U1> as Nat>::reify(), 26);
+        assert_eq!(<Diff<U63, U62> as Nat>::reify(), 1);
+    }
+
+    #[test]
+    fn diff_canonical() {
+        assert_same(PhantomData::<Diff<U8, U3>>, PhantomData::<U5>);
+        assert_same(PhantomData::<Diff<U9, U9>>, PhantomData::<U0>);
+        assert_same(PhantomData::<Diff<U12, U3>>, PhantomData::<U9>);
+        assert_same(PhantomData::<Diff<U27, U1>>, PhantomData::<U26>);
+    }
+
+    // Subtracting from a power of three borrows through every digit, which is the deepest
+    // borrow chain there is.
+    #[test]
+    fn diff_powers_of_three() {
+        assert_same(PhantomData::<Diff<U3, U1>>, PhantomData::<U2>);
+        assert_same(PhantomData::<Diff<U3, U2>>, PhantomData::<U1>);
+        assert_same(PhantomData::<Diff<U9, U1>>, PhantomData::<U8>);
+        assert_same(PhantomData::<Diff<U9, U5>>, PhantomData::<U4>);
+        assert_same(PhantomData::<Diff<U9, U8>>, PhantomData::<U1>);
+        assert_same(PhantomData::<Diff<U27, U13>>, PhantomData::<U14>);
+        assert_same(PhantomData::<Diff<U27, U26>>, PhantomData::<U1>);
+        assert_same(PhantomData::<Diff<U27, U27>>, PhantomData::<U0>);
+        assert_same(PhantomData::<Diff<U28, U2>>, PhantomData::<U26>);
+        assert_same(PhantomData::<Diff<U54, U28>>, PhantomData::<U26>);
+        assert_eq!(<Diff<Product<U9, U9>, U1> as Nat>::reify(), 80);
+        assert_same(PhantomData::<Diff<Product<U9, U9>, U27>>, PhantomData::<Product<U6, U9>>);
+    }
+}
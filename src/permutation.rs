@@ -0,0 +1,93 @@
+use std::marker::PhantomData;
+
+use tll::ternary::Nat;
+
+
+/// The `Permutation` trait describes a fixed reordering of `L` elements, for use with
+/// `Array::apply_permutation`. `get(i)` gives the position in the input which position `i` of the
+/// output is taken from.
+///
+/// This trait is `unsafe` to implement because `apply_permutation` moves elements out of the input
+/// according to `get`, trusting that every position in `0..L` is read exactly once. If `get` is not
+/// a bijection on `0..L`, some element would be moved out twice - and therefore dropped twice.
+pub unsafe trait Permutation<L: Nat> {
+    fn get(i: usize) -> usize;
+}
+
+
+/// The permutation which leaves everything where it is.
+pub struct Identity;
+
+unsafe impl<L: Nat> Permutation<L> for Identity {
+    fn get(i: usize) -> usize {
+        i
+    }
+}
+
+
+/// The permutation which reverses the order of its elements.
+pub struct Reversal;
+
+unsafe impl<L: Nat> Permutation<L> for Reversal {
+    fn get(i: usize) -> usize {
+        L::reify() - 1 - i
+    }
+}
+
+
+/// The permutation which rotates its elements `K` places to the left, so that position `i` of
+/// the output is taken from position `(i + K) % L` of the input.
+pub struct RotateLeft<K: Nat>(PhantomData<K>);
+
+unsafe impl<L: Nat, K: Nat> Permutation<L> for RotateLeft<K> {
+    fn get(i: usize) -> usize {
+        (i + K::reify()) % L::reify()
+    }
+}
+
+
+/// Check that `P` really does describe a bijection on `0..L`. This is only used in debug
+/// assertions, since it needs a scratch allocation.
+pub fn is_bijection<L: Nat, P: Permutation<L>>() -> bool {
+    let mut seen = vec![false; L::reify()];
+    for i in 0..L::reify() {
+        match seen.get_mut(P::get(i)) {
+            Some(seen) if !*seen => *seen = true,
+            _ => return false,
+        }
+    }
+    true
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tll::ternary::*;
+
+    struct Swap01;
+
+    unsafe impl Permutation<U2> for Swap01 {
+        fn get(i: usize) -> usize {
+            1 - i
+        }
+    }
+
+    struct Broken;
+
+    unsafe impl Permutation<U2> for Broken {
+        fn get(_: usize) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn bijections() {
+        assert!(is_bijection::<U0, Identity>());
+        assert!(is_bijection::<U8, Identity>());
+        assert!(is_bijection::<U8, Reversal>());
+        assert!(is_bijection::<U8, RotateLeft<U3>>());
+        assert!(is_bijection::<U2, Swap01>());
+        assert!(!is_bijection::<U2, Broken>());
+    }
+}
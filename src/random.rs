@@ -0,0 +1,103 @@
+//! The `random` module provides randomized operations on `Array`s, when the "rand" feature is
+//! enabled.
+
+use rand::Rng;
+
+use tll::ternary::NatPred;
+
+use array::Array;
+use storage::Arrayify;
+
+
+impl<L: Arrayify<T>, T> Array<L, T> {
+    /// Shuffle the elements of the `Array` into a uniformly random order, using the Fisher-Yates
+    /// algorithm.
+    ///
+    /// This consumes the `Array` and hands back the shuffled one, and so takes the place of
+    /// `SliceRandom::shuffle`, which shuffles in place, even when that trait is imported. For a
+    /// `Copy` element type a discarded result would shuffle a copy, hence the `#[must_use]`; to
+    /// shuffle in place, go through a slice explicitly, as in `array[..].shuffle(rng)`.
+    #[must_use = "this returns the shuffled `Array`, rather than shuffling it in place"]
+    pub fn shuffle<R: Rng + ?Sized>(mut self, rng: &mut R) -> Self {
+        for i in (1..self.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            self.swap(i, j);
+        }
+        self
+    }
+
+    /// Pick an element of the `Array` uniformly at random. Since the `Array` is known to be
+    /// non-empty, there is always something to pick.
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> &T
+        where L: NatPred
+    {
+        &self[rng.gen_range(0..L::reify())]
+    }
+
+    /// Pick an element of the `Array` uniformly at random, moving it out and dropping the rest.
+    pub fn choose_owned<R: Rng + ?Sized>(self, rng: &mut R) -> T
+        where L: NatPred
+    {
+        let i = rng.gen_range(0..L::reify());
+        self.into_iter().nth(i).unwrap()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn shuffle_8() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let array = array![42i32, 84, 126, 168, 210, 252, 294, 336];
+
+        let mut orderings = HashSet::new();
+        for _ in 0..16 {
+            let shuffled = array.shuffle(&mut rng);
+            let mut sorted = shuffled;
+            sorted.sort();
+            assert_eq!(*sorted, *array);
+            orderings.insert(shuffled.to_vec());
+        }
+        assert!(orderings.len() > 1);
+    }
+
+    #[test]
+    fn shuffle_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(*array![String::from("a")].shuffle(&mut rng), ["a"]);
+    }
+
+    #[test]
+    fn choose_uniform() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let array = array![0usize, 1, 2, 3, 4, 5];
+
+        let mut counts = [0; 6];
+        for _ in 0..6000 {
+            counts[*array.choose(&mut rng)] += 1;
+        }
+        for &count in counts.iter() {
+            assert!(count > 850 && count < 1150, "counts were {:?}", counts);
+        }
+    }
+
+    #[test]
+    fn choose_owned_uniform() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            let array = array![String::from("0"), String::from("1"), String::from("2")];
+            counts[array.choose_owned(&mut rng).parse::<usize>().unwrap()] += 1;
+        }
+        for &count in counts.iter() {
+            assert!(count > 850 && count < 1150, "counts were {:?}", counts);
+        }
+    }
+}
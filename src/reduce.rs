@@ -0,0 +1,47 @@
+use std::cmp;
+
+
+/// The `AssociativeOp` trait describes a binary operation which is associative, meaning that
+/// `combine(combine(a, b), c) == combine(a, combine(b, c))`. This is what allows
+/// `Array::windows_reduce_associative` to share partial results between overlapping windows
+/// rather than reducing each window from scratch. The operation need *not* be commutative: the
+/// elements are always combined in order.
+///
+/// Nothing checks that an implementation really is associative. If it isn't, the results will be
+/// wrong, but nothing worse.
+pub trait AssociativeOp<T> {
+    fn combine(a: T, b: T) -> T;
+}
+
+
+/// The associative operation taking the smaller of two elements.
+pub struct Min;
+
+impl<T: Ord> AssociativeOp<T> for Min {
+    fn combine(a: T, b: T) -> T {
+        cmp::min(a, b)
+    }
+}
+
+
+/// The associative operation taking the larger of two elements.
+pub struct Max;
+
+impl<T: Ord> AssociativeOp<T> for Max {
+    fn combine(a: T, b: T) -> T {
+        cmp::max(a, b)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_max() {
+        assert_eq!(<Min as AssociativeOp<i32>>::combine(3, -1), -1);
+        assert_eq!(<Max as AssociativeOp<i32>>::combine(3, -1), 3);
+        assert_eq!(<Max as AssociativeOp<&str>>::combine("a", "b"), "b");
+    }
+}
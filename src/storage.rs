@@ -111,6 +111,14 @@ impl<T: Copy, N: ToArray<T> + Copy> Copy for ZeroNode<T, N> {}
 impl<T: Copy, N: ToArray<T> + Copy> Copy for OneNode<T, N> {}
 impl<T: Copy, N: ToArray<T> + Copy> Copy for TwoNode<T, N> {}
 
+// These are exactly the impls the compiler would derive on its own, since `PhantomData<T>` is
+// `Unpin` whenever `T` is. They're written out only to document that an `Array` is `Unpin` as
+// long as its elements are.
+impl<T: Unpin> Unpin for TermNode<T> {}
+impl<T: Unpin, N: ToArray<T> + Unpin> Unpin for ZeroNode<T, N> {}
+impl<T: Unpin, N: ToArray<T> + Unpin> Unpin for OneNode<T, N> {}
+impl<T: Unpin, N: ToArray<T> + Unpin> Unpin for TwoNode<T, N> {}
+
 
 type_operators! {
     [A, B, C, D, E]